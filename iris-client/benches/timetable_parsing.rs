@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use iris_client::station_board::parser::parse_timetable;
+use iris_client::station_board::response::TimeTable;
+
+const PLAN: &str = include_str!("../tests/fixtures/plan_8000105.xml");
+const REALTIME: &str = include_str!("../tests/fixtures/fchg_8000105.xml");
+
+/// Repeats the stops of a fixture to get a document the size of a `fchg` of a big station like Köln Hbf.
+fn large_timetable(fixture: &str, repetitions: usize) -> String {
+    let start = fixture.find("<s ").expect("Fixture has stops");
+    let end = fixture
+        .rfind("</timetable>")
+        .expect("Fixture is a timetable");
+
+    let mut document = String::from(&fixture[..start]);
+    for _ in 0..repetitions {
+        document.push_str(&fixture[start..end]);
+    }
+    document.push_str(&fixture[end..]);
+    document
+}
+
+fn timetable_parsing(c: &mut Criterion) {
+    let documents = [
+        ("plan", PLAN.to_owned()),
+        ("fchg", REALTIME.to_owned()),
+        ("fchg_large", large_timetable(REALTIME, 200)),
+    ];
+
+    let mut group = c.benchmark_group("timetable_parsing");
+
+    for (name, document) in &documents {
+        group.bench_with_input(
+            BenchmarkId::new("serde_xml_rs", name),
+            document,
+            |b, document| {
+                b.iter(|| serde_xml_rs::from_str::<TimeTable>(black_box(document)).unwrap())
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("quick_xml", name),
+            document,
+            |b, document| b.iter(|| parse_timetable(black_box(document)).unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, timetable_parsing);
+criterion_main!(benches);
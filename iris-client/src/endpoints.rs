@@ -1 +1,4 @@
+pub mod disruptions;
+pub mod journey;
 pub mod station_board;
+pub mod station_lookup;
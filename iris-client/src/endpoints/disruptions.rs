@@ -0,0 +1,23 @@
+mod r#return;
+
+use chrono::Utc;
+
+pub use r#return::*;
+
+use crate::{IrisClient, IrisOrRequestError};
+
+impl IrisClient {
+    /// Get the disruptions that currently affect a station or will affect it in the future.
+    ///
+    /// Takes the eva number of the station e.G. `8000105` for Frankfurt(Main)Hbf.
+    ///
+    /// Disruptions that IRIS returned invalid data for are skipped and listed in the `warnings`.
+    pub async fn station_disruptions(
+        &self,
+        eva: &str,
+    ) -> Result<IrisStationDisruptions, IrisOrRequestError> {
+        let realtime = self.realtime_station_board(eva).await?;
+
+        Ok(from_realtime_timetable(eva, realtime, Utc::now()))
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::station_board::{response::TimeTable, StationDisruption};
+use crate::IrisTransformError;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IrisStationDisruptions {
+    pub station_name: String,
+    pub station_eva: String,
+    /// Disruptions that are currently valid, ordered by their start
+    pub active: Vec<StationDisruption>,
+    /// Disruptions that will become valid in the future, ordered by their start
+    pub upcoming: Vec<StationDisruption>,
+    /// Disruptions that were skipped because IRIS returned invalid data for them
+    #[serde(default)]
+    pub warnings: Vec<IrisTransformError>,
+}
+
+/// Splits the disruptions in the `realtime` information of a station into active and upcoming disruptions at the time `now`.
+///
+/// Disruptions that already ended at `now` are dropped.
+pub fn from_realtime_timetable<T: TimeZone>(
+    station_eva: &str,
+    realtime: TimeTable,
+    now: DateTime<T>,
+) -> IrisStationDisruptions {
+    let mut warnings = Vec::new();
+    let mut active = Vec::new();
+    let mut upcoming = Vec::new();
+
+    for message in realtime.disruptions {
+        let mut disruption = match StationDisruption::try_from(message) {
+            Ok(disruption) => disruption,
+            Err(err) => {
+                warnings.push(err);
+                continue;
+            }
+        };
+
+        disruption.active = disruption.is_active_at(&now);
+
+        if disruption.active {
+            active.push(disruption);
+        } else if !disruption.has_ended_at(&now) {
+            upcoming.push(disruption);
+        }
+    }
+
+    active.sort_by_key(|disruption| disruption.valid_from);
+    upcoming.sort_by_key(|disruption| disruption.valid_from);
+
+    IrisStationDisruptions {
+        station_name: realtime.station_name,
+        station_eva: String::from(station_eva),
+        active,
+        upcoming,
+        warnings,
+    }
+}
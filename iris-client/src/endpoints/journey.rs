@@ -0,0 +1,298 @@
+mod r#return;
+
+pub use r#return::*;
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Duration};
+use chrono_tz::Tz;
+
+use crate::helpers::{hour_slots, is_stop_of_trip, parse_iris_date};
+use crate::station_board::{
+    from_iris_realtime, from_iris_timetable, response::TimeTable, StationBoardStop, TransformMode,
+};
+use crate::{
+    source::IrisSource, IrisClient, IrisOrRequestError, IrisTransformError, IrisUnavailableStation,
+};
+
+/// How many hours after the start of a trip its stops are searched for.
+const MAX_JOURNEY_HOURS: i64 = 12;
+
+impl IrisClient {
+    /// Reconstructs the journey of a train from the station boards of the stations on its route.
+    ///
+    /// Takes the trip id (or the id of any stop of the trip) e.G. `-7874571842864554321-1403311221` \
+    /// and the eva number of a station the train stops at e.G. `8000105` for Frankfurt(Main)Hbf.
+    ///
+    /// See [`journey_from`] for how the journey is reconstructed.
+    pub async fn journey(
+        &self,
+        trip_id: &str,
+        eva: &str,
+    ) -> Result<IrisJourney, IrisOrRequestError> {
+        journey_from(self, trip_id, eva).await
+    }
+}
+
+/// Reconstructs the journey of a train with the data of `source`.
+///
+/// The stop of the trip at the station `eva` is searched first, \
+/// then all other stations on its route are looked up by their name and searched for the stop of the trip at the same time, \
+/// earlier stations before the train arrives at `eva` and later stations after it departs. \
+/// Stations IRIS has no stop of the trip for and stations whose name matches no station exactly are listed in `missing_stations`, \
+/// stations whose data can not be fetched are also listed in `unavailable_stations`. \
+/// Only a failure to fetch the data of `eva` itself fails the journey.
+pub async fn journey_from<S: IrisSource + ?Sized>(
+    source: &S,
+    trip_id: &str,
+    eva: &str,
+) -> Result<IrisJourney, IrisOrRequestError> {
+    let (trip_id, start) =
+        parse_trip_id(trip_id).ok_or_else(|| IrisOrRequestError::InvalidTripId {
+            trip_id: trip_id.to_owned(),
+        })?;
+    let end = start + Duration::hours(MAX_JOURNEY_HOURS);
+
+    let mut warnings = Vec::new();
+
+    let anchor = find_journey_stop(source, eva, &trip_id, start, end, false, &mut warnings)
+        .await?
+        .ok_or_else(|| IrisOrRequestError::TripNotFound {
+            trip_id: trip_id.to_owned(),
+            eva: eva.to_owned(),
+        })?;
+
+    let current = anchor
+        .route
+        .iter()
+        .position(|route_stop| route_stop.name == anchor.station_name)
+        .unwrap_or_default();
+
+    let arrival = stop_time(&anchor, false);
+    let departure = stop_time(&anchor, true);
+
+    let found = {
+        let anchor = &anchor;
+        let trip_id = trip_id.as_str();
+
+        futures::future::join_all(anchor.route.iter().enumerate().map(
+            |(index, route_stop)| async move {
+                let mut warnings = Vec::new();
+
+                let stop = match index.cmp(&current) {
+                    Ordering::Less => {
+                        find_station_journey_stop(
+                            source,
+                            &route_stop.name,
+                            trip_id,
+                            start,
+                            arrival,
+                            true,
+                            &mut warnings,
+                        )
+                        .await
+                    }
+                    Ordering::Equal => Ok(Some(anchor.clone())),
+                    Ordering::Greater => {
+                        find_station_journey_stop(
+                            source,
+                            &route_stop.name,
+                            trip_id,
+                            departure,
+                            end,
+                            false,
+                            &mut warnings,
+                        )
+                        .await
+                    }
+                };
+
+                (route_stop.name.to_owned(), stop, warnings)
+            },
+        ))
+        .await
+    };
+
+    let mut stops = Vec::new();
+    let mut missing_stations = Vec::new();
+    let mut unavailable_stations = Vec::new();
+
+    for (name, stop, stop_warnings) in found {
+        warnings.extend(stop_warnings);
+
+        match stop {
+            Ok(Some(stop)) => stops.push(stop),
+            Ok(None) => missing_stations.push(name),
+            Err(err) => {
+                unavailable_stations.push(IrisUnavailableStation {
+                    station: name.clone(),
+                    reason: err.to_string(),
+                });
+                missing_stations.push(name);
+            }
+        }
+    }
+
+    Ok(IrisJourney {
+        trip_id,
+        train_type: anchor.train_type,
+        train_number: anchor.train_number,
+        line_indicator: anchor.line_indicator,
+        stops: stops.into_iter().map(IrisJourneyStop::from).collect(),
+        missing_stations,
+        warnings,
+        unavailable_stations,
+    })
+}
+
+/// Looks up the eva number of the station with the name `station_name` and searches it for the stop of the trip.
+///
+/// Returns `None` if no station has exactly that name, so no stop of another station is attached to the journey.
+async fn find_station_journey_stop<S: IrisSource + ?Sized>(
+    source: &S,
+    station_name: &str,
+    trip_id: &str,
+    from: DateTime<Tz>,
+    to: DateTime<Tz>,
+    backwards: bool,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Result<Option<StationBoardStop>, IrisOrRequestError> {
+    let stations = source.stations(station_name).await?;
+
+    let Some(station) = stations.iter().find(|station| station.name == station_name) else {
+        return Ok(None);
+    };
+
+    find_journey_stop(source, &station.eva, trip_id, from, to, backwards, warnings).await
+}
+
+/// Searches the hour slots from `from` to `to` (latest first if `backwards`) for the stop of the trip, \
+/// falling back to the realtime information for trips IRIS has no planned data for.
+async fn find_journey_stop<S: IrisSource + ?Sized>(
+    source: &S,
+    eva: &str,
+    trip_id: &str,
+    from: DateTime<Tz>,
+    to: DateTime<Tz>,
+    backwards: bool,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Result<Option<StationBoardStop>, IrisOrRequestError> {
+    let realtime = source.realtime(eva).await?;
+
+    let mut slots = hour_slots(from, to).collect::<Vec<_>>();
+    if backwards {
+        slots.reverse();
+    }
+
+    for (date, hour) in slots {
+        let Ok(plan) = source.planned(eva, &date, &hour).await else {
+            continue;
+        };
+
+        if let Some(stop) = find_planned_trip_stop(eva, trip_id, &plan, &realtime, warnings)? {
+            return Ok(Some(stop));
+        }
+    }
+
+    Ok(find_realtime_trip_stop(eva, trip_id, &realtime, warnings)?)
+}
+
+/// Splits a trip id (or stop id) into the trip id and the planned start of the trip.
+pub fn parse_trip_id(id: &str) -> Option<(String, DateTime<Tz>)> {
+    let (negative, unsigned) = match id.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, id),
+    };
+
+    let mut parts = unsigned.split('-');
+    let daily_trip_id = parts.next().filter(|part| !part.is_empty())?;
+    let date = parts.next()?;
+
+    if parts.nth(1).is_some() {
+        return None;
+    }
+
+    let start = parse_iris_date(date)?;
+    let sign = if negative { "-" } else { "" };
+
+    Some((format!("{sign}{daily_trip_id}-{date}"), start))
+}
+
+/// Finds the stop of the trip in a planned timetable and combines it with its realtime information.
+///
+/// Invalid messages of the stop are skipped and added to `warnings`.
+pub fn find_planned_trip_stop(
+    station_eva: &str,
+    trip_id: &str,
+    plan: &TimeTable,
+    realtime: &TimeTable,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Result<Option<StationBoardStop>, IrisTransformError> {
+    let Some(stop) = plan
+        .stops
+        .iter()
+        .find(|stop| is_stop_of_trip(&stop.id, trip_id))
+    else {
+        return Ok(None);
+    };
+
+    let realtime_stop = realtime
+        .stops
+        .iter()
+        .find(|realtime_stop| realtime_stop.id == stop.id)
+        .cloned();
+
+    from_iris_timetable(
+        station_eva,
+        &plan.station_name,
+        stop.clone(),
+        realtime_stop,
+        TransformMode::Lenient,
+        warnings,
+    )
+    .map(Some)
+}
+
+/// Finds the stop of a trip that IRIS only knows about through its realtime data (e.G. an added train).
+///
+/// Invalid messages of the stop are skipped and added to `warnings`.
+pub fn find_realtime_trip_stop(
+    station_eva: &str,
+    trip_id: &str,
+    realtime: &TimeTable,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Result<Option<StationBoardStop>, IrisTransformError> {
+    let Some(stop) = realtime
+        .stops
+        .iter()
+        .find(|stop| is_stop_of_trip(&stop.id, trip_id))
+    else {
+        return Ok(None);
+    };
+
+    from_iris_realtime(
+        station_eva,
+        &realtime.station_name,
+        stop.clone(),
+        TransformMode::Lenient,
+        warnings,
+    )
+}
+
+/// The planned departure (or arrival) time of a stop, falling back to the arrival (or departure).
+fn stop_time(stop: &StationBoardStop, departure: bool) -> DateTime<Tz> {
+    let departure_time = stop
+        .departure
+        .as_ref()
+        .map(|departure| departure.planned_time);
+    let arrival_time = stop.arrival.as_ref().map(|arrival| arrival.planned_time);
+
+    let time = if departure {
+        departure_time.or(arrival_time)
+    } else {
+        arrival_time.or(departure_time)
+    };
+
+    time.map(|time| time.with_timezone(&chrono_tz::Europe::Berlin))
+        .unwrap_or_else(|| chrono::Utc::now().with_timezone(&chrono_tz::Europe::Berlin))
+}
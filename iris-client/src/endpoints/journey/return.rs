@@ -0,0 +1,88 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::station_board::{message::Message, StationBoardStop};
+use crate::{IrisTransformError, IrisUnavailableStation};
+
+/// The run of a train reconstructed from the station boards of the stations on its route.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IrisJourney {
+    /// The trip id, e.G. `-7874571842864554321-1403311221`
+    pub trip_id: String,
+    pub train_type: String,
+    pub train_number: String,
+    pub line_indicator: String,
+    /// The stops of the journey in the order the train calls at them
+    pub stops: Vec<IrisJourneyStop>,
+    /// The names of the stations on the route IRIS had no stop of this trip for
+    pub missing_stations: Vec<String>,
+    /// Messages that were skipped because IRIS returned invalid data for them
+    #[serde(default)]
+    pub warnings: Vec<IrisTransformError>,
+    /// The stations on the route whose data could not be fetched, they are also listed in `missing_stations`
+    #[serde(default)]
+    pub unavailable_stations: Vec<IrisUnavailableStation>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IrisJourneyStop {
+    pub stop_id: String,
+    /// The index of the stop within the trip, added stops have indices above 100
+    pub index: u32,
+    pub station_eva: String,
+    pub station_name: String,
+    #[schema(nullable)]
+    pub arrival: Option<IrisJourneyStopEvent>,
+    #[schema(nullable)]
+    pub departure: Option<IrisJourneyStopEvent>,
+    #[schema(nullable)]
+    pub planned_platform: Option<String>,
+    #[schema(nullable)]
+    pub real_platform: Option<String>,
+    pub cancelled: bool,
+    pub added: bool,
+    pub messages: Vec<Message>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IrisJourneyStopEvent {
+    pub planned_time: DateTime<FixedOffset>,
+    #[schema(nullable)]
+    pub real_time: Option<DateTime<FixedOffset>>,
+    #[schema(nullable)]
+    pub delay_minutes: Option<i64>,
+}
+
+impl From<StationBoardStop> for IrisJourneyStop {
+    fn from(stop: StationBoardStop) -> Self {
+        Self {
+            index: stop
+                .id
+                .rsplit_once('-')
+                .and_then(|(_, index)| index.parse().ok())
+                .unwrap_or_default(),
+            stop_id: stop.id,
+            station_eva: stop.station_eva,
+            station_name: stop.station_name,
+            arrival: stop.arrival.map(|arrival| IrisJourneyStopEvent {
+                planned_time: arrival.planned_time,
+                real_time: arrival.real_time,
+                delay_minutes: stop.arrival_delay_minutes,
+            }),
+            departure: stop.departure.map(|departure| IrisJourneyStopEvent {
+                planned_time: departure.planned_time,
+                real_time: departure.real_time,
+                delay_minutes: stop.departure_delay_minutes,
+            }),
+            planned_platform: stop.planned_platform,
+            real_platform: stop.real_platform,
+            cancelled: stop.cancelled,
+            added: stop.added,
+            messages: stop.messages,
+        }
+    }
+}
@@ -1,19 +1,26 @@
+#[cfg(feature = "quick-xml")]
+pub mod parser;
 pub mod response;
 mod r#return;
 
-use chrono::{DateTime, Duration, TimeZone, Timelike};
+use chrono::{DateTime, TimeZone};
 use chrono_tz::{Europe::Berlin, Tz};
 
 pub use r#return::*;
 
 use response::*;
 
-use crate::{IrisClient, IrisError, IrisOrRequestError};
+use crate::{
+    helpers::hour_slots, source::IrisSource, station_lookup::meta_stations, IrisClient, IrisError,
+    IrisOrRequestError, IrisUnavailableStation,
+};
 
 impl IrisClient {
     /// Fetches all planned information IRIS has for a specific station at the specified time frame \
     /// and the realtime information IRIS currently has for the specified station and combines them into a \
     /// better format that is easier to work with.
+    ///
+    /// Stops that IRIS returned invalid data for are skipped and listed in the `warnings` of the station board.
     pub async fn station_board(
         &self,
         eva: &str,
@@ -21,68 +28,25 @@ impl IrisClient {
         lookahead: Option<u32>,
         lookbehind: Option<u32>,
     ) -> Result<IrisStationBoard, IrisOrRequestError> {
-        let date =
-            date.unwrap_or_else(|| Berlin.from_utc_datetime(&chrono::Utc::now().naive_utc()));
-
-        let lookbehind = lookbehind.unwrap_or(20);
-        let lookahead = lookahead.unwrap_or(180);
-
-        let lookbehind = date - chrono::Duration::minutes(lookbehind as i64);
-        let lookahead = date + chrono::Duration::minutes(lookahead as i64);
+        let (start, end) = time_frame(date, lookahead, lookbehind);
 
-        let mut dates = Vec::new();
-
-        for current_date in DateRange(lookbehind, lookahead) {
-            dates.push(current_date);
-        }
+        station_board_from(self, eva, start, end).await
+    }
 
-        let (realtime, timetables) = tokio::join!(
-            self.realtime_station_board(eva),
-            futures::future::join_all(dates.iter().map(|date| async move {
-                self.planned_station_board(
-                    eva,
-                    &date.format("%y%m%d").to_string(),
-                    &date.format("%H").to_string(),
-                )
-                .await
-            }))
-        );
-
-        let realtime = realtime?;
-        let timetables = timetables
-            .into_iter()
-            .filter_map(|result| result.ok())
-            .collect::<Vec<_>>();
-
-        let disruptions = realtime
-            .disruptions
-            .into_iter()
-            .map(|message| message.into())
-            .collect::<Vec<r#return::Message>>();
-
-        let mut stops = Vec::new();
-
-        for timetable in timetables {
-            for stop in timetable.stops {
-                let realtime = realtime
-                    .stops
-                    .iter()
-                    .find(|realtime_stop| realtime_stop.id == stop.id);
-                stops.push(from_iris_timetable(
-                    eva,
-                    &timetable.station_name,
-                    stop,
-                    realtime.map(|realtime| realtime.to_owned()),
-                ));
-            }
-        }
+    /// Like [`station_board`](IrisClient::station_board), but also includes the stops of all meta stations of the station \
+    /// (e.G. the S-Bahn platforms of Frankfurt(Main)Hbf which have their own eva number).
+    ///
+    /// Every stop keeps the `station_eva` of the station it actually belongs to.
+    pub async fn meta_station_board(
+        &self,
+        eva: &str,
+        date: Option<DateTime<Tz>>,
+        lookahead: Option<u32>,
+        lookbehind: Option<u32>,
+    ) -> Result<IrisStationBoard, IrisOrRequestError> {
+        let (start, end) = time_frame(date, lookahead, lookbehind);
 
-        Ok(IrisStationBoard {
-            station_name: realtime.station_name,
-            station_eva: String::from(eva),
-            disruptions,
-            stops,
-        })
+        meta_station_board_from(self, eva, start, end).await
     }
 
     /// Get all realtime information IRIS currently has for a specific station.
@@ -105,9 +69,30 @@ impl IrisClient {
 
         let response: String = response.text().await?;
 
-        let response = serde_xml_rs::from_str(&response)?;
+        parse_timetable(&response)
+    }
+
+    /// Get all realtime changes IRIS received within the last two minutes for a specific station.
+    ///
+    /// Meant to be applied on top of the full realtime information, **consider using a [`RealtimeStore`](crate::realtime_store::RealtimeStore) for that.** \
+    ///
+    /// Takes the eva number of the station e.G. `8000105` for Frankfurt(Main)Hbf.
+    pub async fn recent_changes(&self, eva: &str) -> Result<TimeTable, IrisOrRequestError> {
+        let _permit = self.semaphore.acquire().await;
 
-        Ok(response)
+        let response = self
+            .client
+            .get(format!("{}/iris-tts/timetable/rchg/{}", self.base_url, eva))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(IrisOrRequestError::IrisError(IrisError));
+        }
+
+        let response: String = response.text().await?;
+
+        parse_timetable(&response)
     }
 
     /// Get all planned information IRIS has for a specific station at the specified date + hour.
@@ -142,20 +127,108 @@ impl IrisClient {
 
         let response: String = response.text().await?;
 
-        Ok(serde_xml_rs::from_str(&response)?)
+        parse_timetable(&response)
     }
 }
 
-struct DateRange(DateTime<Tz>, DateTime<Tz>);
+/// The time frame from `lookbehind` minutes (default 20) before `date` (default now) to `lookahead` minutes (default 180) after it.
+fn time_frame(
+    date: Option<DateTime<Tz>>,
+    lookahead: Option<u32>,
+    lookbehind: Option<u32>,
+) -> (DateTime<Tz>, DateTime<Tz>) {
+    let date = date.unwrap_or_else(|| Berlin.from_utc_datetime(&chrono::Utc::now().naive_utc()));
+
+    let lookbehind = lookbehind.unwrap_or(20);
+    let lookahead = lookahead.unwrap_or(180);
+
+    (
+        date - chrono::Duration::minutes(lookbehind as i64),
+        date + chrono::Duration::minutes(lookahead as i64),
+    )
+}
 
-impl Iterator for DateRange {
-    type Item = DateTime<Tz>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.0 <= self.1 || self.0.hour() == self.1.hour() {
-            let next = self.0 + Duration::hours(1);
-            Some(std::mem::replace(&mut self.0, next))
-        } else {
-            None
+/// Builds the station board of a station from `start` to `end` with the data of `source`.
+///
+/// Hours IRIS has no planned data for are skipped, an error is only returned if the realtime information can not be fetched.
+pub async fn station_board_from<S: IrisSource + ?Sized>(
+    source: &S,
+    eva: &str,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+) -> Result<IrisStationBoard, IrisOrRequestError> {
+    let slots = hour_slots(start, end).collect::<Vec<_>>();
+
+    let (realtime, timetables) = tokio::join!(
+        source.realtime(eva),
+        futures::future::join_all(
+            slots
+                .iter()
+                .map(|(date, hour)| source.planned(eva, date, hour))
+        )
+    );
+
+    let realtime = realtime?;
+    let timetables = timetables
+        .into_iter()
+        .filter_map(|result| result.ok())
+        .collect::<Vec<_>>();
+
+    Ok(from_iris_timetables(
+        eva,
+        timetables,
+        realtime,
+        start,
+        end,
+        TransformMode::Lenient,
+    )?)
+}
+
+/// Like [`station_board_from`], but also merges the station boards of all meta stations of the station into it.
+///
+/// Meta stations whose station board can not be fetched are skipped and listed in the `unavailable_stations` of the station board.
+pub async fn meta_station_board_from<S: IrisSource + ?Sized>(
+    source: &S,
+    eva: &str,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+) -> Result<IrisStationBoard, IrisOrRequestError> {
+    let stations = source.stations(eva).await?;
+    let meta_stations = meta_stations(eva, &stations);
+
+    let (station_board, meta_station_boards) = tokio::join!(
+        station_board_from(source, eva, start, end),
+        futures::future::join_all(
+            meta_stations
+                .iter()
+                .map(|meta_eva| station_board_from(source, meta_eva, start, end))
+        )
+    );
+
+    let mut station_board = station_board?;
+
+    for (meta_eva, meta_station_board) in meta_stations.into_iter().zip(meta_station_boards) {
+        match meta_station_board {
+            Ok(meta_station_board) => station_board.merge(meta_station_board),
+            Err(err) => station_board
+                .unavailable_stations
+                .push(IrisUnavailableStation {
+                    station: meta_eva,
+                    reason: err.to_string(),
+                }),
         }
     }
+
+    Ok(station_board)
+}
+
+/// Parses a timetable with the `quick-xml` parser if the `quick-xml` feature is enabled, otherwise with `serde_xml_rs`.
+fn parse_timetable(xml: &str) -> Result<TimeTable, IrisOrRequestError> {
+    #[cfg(feature = "quick-xml")]
+    let timetable = parser::parse_timetable(xml)?;
+
+    #[cfg(not(feature = "quick-xml"))]
+    let timetable = serde_xml_rs::from_str(xml)?;
+
+    Ok(timetable)
 }
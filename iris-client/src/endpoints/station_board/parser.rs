@@ -0,0 +1,633 @@
+//! A pull parser for the timetables IRIS returns, built on [`quick_xml`].
+//!
+//! Produces exactly the same [`TimeTable`] as deserializing with `serde_xml_rs`, \
+//! but reads the document in a single pass without building an intermediate tree, \
+//! which is a lot faster for big stations.
+
+use std::borrow::Cow;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::IrisXmlError;
+
+use super::response::*;
+
+type Result<T> = std::result::Result<T, IrisXmlError>;
+
+/// Parses a timetable (`plan`, `fchg` or `rchg`) returned by IRIS.
+pub fn parse_timetable(xml: &str) -> Result<TimeTable> {
+    let mut parser = Parser {
+        reader: Reader::from_str(xml),
+    };
+    parser.reader.trim_text(true);
+
+    loop {
+        match parser.next()? {
+            Event::Start(element) if element.name().as_ref() == b"timetable" => {
+                return parser.timetable(&element, false)
+            }
+            Event::Empty(element) if element.name().as_ref() == b"timetable" => {
+                return parser.timetable(&element, true)
+            }
+            Event::Start(element) => parser.skip(&element, false)?,
+            Event::Eof => return Err(parser.error("missing timetable element")),
+            _ => {}
+        }
+    }
+}
+
+struct Parser<'a> {
+    reader: Reader<&'a [u8]>,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Result<Event<'a>> {
+        self.reader
+            .read_event()
+            .map_err(|err| self.error(&err.to_string()))
+    }
+
+    fn error(&self, message: &str) -> IrisXmlError {
+        IrisXmlError {
+            message: message.to_owned(),
+            position: self.reader.buffer_position(),
+        }
+    }
+
+    fn skip(&mut self, element: &BytesStart, empty: bool) -> Result<()> {
+        if !empty {
+            self.reader
+                .read_to_end(element.name())
+                .map_err(|err| self.error(&err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Calls `on_child` for every direct child element until the end of the current element.
+    fn children(
+        &mut self,
+        empty: bool,
+        mut on_child: impl FnMut(&mut Self, BytesStart<'a>, bool) -> Result<()>,
+    ) -> Result<()> {
+        if empty {
+            return Ok(());
+        }
+
+        loop {
+            match self.next()? {
+                Event::Start(element) => on_child(self, element, false)?,
+                Event::Empty(element) => on_child(self, element, true)?,
+                Event::End(_) => return Ok(()),
+                Event::Eof => return Err(self.error("unexpected end of document")),
+                _ => {}
+            }
+        }
+    }
+
+    /// Calls `on_attribute` with the name and the unescaped value of every attribute of `element`.
+    fn attributes(
+        &self,
+        element: &BytesStart,
+        mut on_attribute: impl FnMut(&[u8], Cow<str>) -> Result<()>,
+    ) -> Result<()> {
+        for attribute in element.attributes() {
+            let attribute = attribute.map_err(|err| self.error(&err.to_string()))?;
+            let value = attribute
+                .unescape_value()
+                .map_err(|err| self.error(&err.to_string()))?;
+            on_attribute(attribute.key.as_ref(), value)?;
+        }
+        Ok(())
+    }
+
+    fn required<T>(&self, value: Option<T>, attribute: &str, element: &str) -> Result<T> {
+        value.ok_or_else(|| self.error(&format!("missing field `{attribute}` on `{element}`")))
+    }
+
+    fn timetable(&mut self, element: &BytesStart, empty: bool) -> Result<TimeTable> {
+        let mut timetable = TimeTable {
+            station_name: String::new(),
+            eva: None,
+            disruptions: Vec::new(),
+            stops: Vec::new(),
+        };
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"station" => timetable.station_name = value.into_owned(),
+                b"eva" => timetable.eva = Some(value.into_owned()),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"m" => timetable.disruptions.push(parser.message(&child, empty)?),
+                b"s" => timetable.stops.push(parser.stop(&child, empty)?),
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(timetable)
+    }
+
+    fn stop(&mut self, element: &BytesStart, empty: bool) -> Result<TimetableStop> {
+        let mut id = None;
+        let mut eva_number = None;
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"id" => id = Some(value.into_owned()),
+                b"eva" => eva_number = Some(value.into_owned()),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        let mut stop = TimetableStop {
+            id: self.required(id, "id", "s")?,
+            eva_number,
+            departure: None,
+            arrival: None,
+            trip_label: None,
+            messages: None,
+            connection: Vec::new(),
+            reference: None,
+            reference_trip_relation: None,
+        };
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"dp" => stop.departure = Some(parser.arrival_departure(&child, empty)?),
+                b"ar" => stop.arrival = Some(parser.arrival_departure(&child, empty)?),
+                b"tl" => stop.trip_label = Some(parser.trip_label(&child, empty, "tl")?),
+                b"m" => stop
+                    .messages
+                    .get_or_insert_with(Vec::new)
+                    .push(parser.message(&child, empty)?),
+                b"conn" => stop.connection.push(parser.connection(&child, empty)?),
+                b"ref" => stop.reference = Some(parser.reference(empty)?),
+                b"rtr" => {
+                    stop.reference_trip_relation =
+                        Some(parser.reference_trip_relation(&child, empty)?)
+                }
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(stop)
+    }
+
+    fn arrival_departure(&mut self, element: &BytesStart, empty: bool) -> Result<ArrivalDeparture> {
+        let mut event = ArrivalDeparture {
+            changed_distant_endpoint: None,
+            cancellation_time: None,
+            changed_platform: None,
+            changed_path: None,
+            real_event_status: None,
+            changed_time: None,
+            distant_change: None,
+            hidden: None,
+            line_indicator: None,
+            messages: Vec::new(),
+            planned_distant_endpoint: None,
+            planned_platform: None,
+            planned_path: None,
+            planned_event_status: None,
+            planned_time: None,
+            transition: None,
+            wings: None,
+        };
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"cde" => event.changed_distant_endpoint = Some(value.into_owned()),
+                b"clt" => event.cancellation_time = Some(value.into_owned()),
+                b"cp" => event.changed_platform = Some(value.into_owned()),
+                b"cpth" => event.changed_path = Some(value.into_owned()),
+                b"cs" => event.real_event_status = Some(self.event_status(&value)?),
+                b"ct" => event.changed_time = Some(value.into_owned()),
+                b"dc" => event.distant_change = Some(self.number(&value)?),
+                b"hi" => event.hidden = Some(self.number(&value)?),
+                b"l" => event.line_indicator = Some(value.into_owned()),
+                b"pde" => event.planned_distant_endpoint = Some(value.into_owned()),
+                b"pp" => event.planned_platform = Some(value.into_owned()),
+                b"ppth" => event.planned_path = Some(value.into_owned()),
+                b"ps" => event.planned_event_status = Some(self.event_status(&value)?),
+                b"pt" => event.planned_time = Some(value.into_owned()),
+                b"tra" => event.transition = Some(value.into_owned()),
+                b"wings" => event.wings = Some(value.into_owned()),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"m" => event.messages.push(parser.message(&child, empty)?),
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(event)
+    }
+
+    fn message(&mut self, element: &BytesStart, empty: bool) -> Result<Message> {
+        let mut id = None;
+        let mut message_status = None;
+        let mut timestamp = None;
+        let mut code = None;
+        let mut category = None;
+        let mut deleted = None;
+        let mut external_category = None;
+        let mut external_link = None;
+        let mut external_text = None;
+        let mut valid_from = None;
+        let mut valid_to = None;
+        let mut internal_text = None;
+        let mut owner = None;
+        let mut priority = None;
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"id" => id = Some(value.into_owned()),
+                b"c" => code = Some(self.number(&value)?),
+                b"cat" => category = Some(value.into_owned()),
+                b"del" => deleted = Some(self.number(&value)?),
+                b"ec" => external_category = Some(value.into_owned()),
+                b"elnk" => external_link = Some(value.into_owned()),
+                b"ext" => external_text = Some(value.into_owned()),
+                b"from" => valid_from = Some(value.into_owned()),
+                b"to" => valid_to = Some(value.into_owned()),
+                b"int" => internal_text = Some(value.into_owned()),
+                b"o" => owner = Some(value.into_owned()),
+                b"pr" => priority = Some(self.message_priority(&value)?),
+                b"t" => message_status = Some(self.message_status(&value)?),
+                b"ts" => timestamp = Some(value.into_owned()),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        let mut message = Message {
+            id: self.required(id, "id", "m")?,
+            code,
+            category,
+            deleted,
+            external_category,
+            external_link,
+            external_text,
+            valid_from,
+            valid_to,
+            internal_text,
+            owner,
+            priority,
+            message_status: self.required(message_status, "t", "m")?,
+            trip_label: None,
+            distributor_messages: None,
+            timestamp: self.required(timestamp, "ts", "m")?,
+        };
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"tl" => message
+                    .trip_label
+                    .get_or_insert_with(Vec::new)
+                    .push(parser.trip_label(&child, empty, "tl")?),
+                b"dm" => message
+                    .distributor_messages
+                    .get_or_insert_with(Vec::new)
+                    .push(parser.distributor_message(&child, empty)?),
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(message)
+    }
+
+    fn distributor_message(
+        &mut self,
+        element: &BytesStart,
+        empty: bool,
+    ) -> Result<DistributorMessage> {
+        let mut message = DistributorMessage {
+            internal_text: None,
+            distributor_name: None,
+            distributor_type: None,
+            timestamp: None,
+        };
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"int" => message.internal_text = Some(value.into_owned()),
+                b"n" => message.distributor_name = Some(value.into_owned()),
+                b"t" => message.distributor_type = Some(self.distributor_type(&value)?),
+                b"ts" => message.timestamp = Some(value.into_owned()),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        self.skip(element, empty)?;
+
+        Ok(message)
+    }
+
+    fn trip_label(&mut self, element: &BytesStart, empty: bool, name: &str) -> Result<TripLabel> {
+        let mut category = None;
+        let mut filter_flags = None;
+        let mut train_number = None;
+        let mut owner = None;
+        let mut trip_type = None;
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"c" => category = Some(value.into_owned()),
+                b"f" => filter_flags = Some(value.into_owned()),
+                b"n" => train_number = Some(value.into_owned()),
+                b"o" => owner = Some(value.into_owned()),
+                b"t" => trip_type = Some(self.trip_type(&value)?),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        self.skip(element, empty)?;
+
+        Ok(TripLabel {
+            category: self.required(category, "c", name)?,
+            filter_flags,
+            train_number: self.required(train_number, "n", name)?,
+            owner: self.required(owner, "o", name)?,
+            trip_type,
+        })
+    }
+
+    fn reference(&mut self, empty: bool) -> Result<Reference> {
+        let mut reffered_trips = Vec::new();
+        let mut trip_label = None;
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"rt" => reffered_trips.push(parser.trip_label(&child, empty, "rt")?),
+                b"tl" => trip_label = Some(parser.trip_label(&child, empty, "tl")?),
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(Reference {
+            reffered_trips,
+            trip_label: self.required(trip_label, "tl", "ref")?,
+        })
+    }
+
+    fn reference_trip_relation(
+        &mut self,
+        element: &BytesStart,
+        empty: bool,
+    ) -> Result<ReferenceTripRelation> {
+        let mut relation = None;
+        let mut real_trip = None;
+
+        self.attributes(element, |key, value| {
+            if key == b"rts" {
+                relation = Some(self.relation_type(&value)?);
+            }
+            Ok(())
+        })?;
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"rt" => real_trip = Some(parser.real_trip(&child, empty)?),
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(ReferenceTripRelation {
+            real_trip: self.required(real_trip, "rt", "rtr")?,
+            relation: self.required(relation, "rts", "rtr")?,
+        })
+    }
+
+    fn real_trip(&mut self, element: &BytesStart, empty: bool) -> Result<RealTrip> {
+        let mut id = None;
+        let mut cancelled = None;
+        let mut reference_trip_stop = None;
+        let mut reference_trip = None;
+        let mut sd = None;
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"id" => id = Some(value.into_owned()),
+                b"c" => {
+                    cancelled = Some(match value.as_ref() {
+                        "true" | "1" => true,
+                        "false" | "0" => false,
+                        _ => return Err(self.error(&format!("invalid boolean `{value}`"))),
+                    })
+                }
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"ea" => {
+                    reference_trip_stop = Some(parser.reference_trip_stop(&child, empty, "ea")?)
+                }
+                b"sd" => sd = Some(parser.reference_trip_stop(&child, empty, "sd")?),
+                b"rtl" => {
+                    let mut category = None;
+                    let mut number = None;
+                    parser.attributes(&child, |key, value| {
+                        match key {
+                            b"c" => category = Some(value.into_owned()),
+                            b"n" => number = Some(value.into_owned()),
+                            _ => {}
+                        }
+                        Ok(())
+                    })?;
+                    parser.skip(&child, empty)?;
+                    reference_trip = Some(ReferenceTripCharacterization {
+                        category: parser.required(category, "c", "rtl")?,
+                        number: parser.required(number, "n", "rtl")?,
+                    });
+                }
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(RealTrip {
+            id: self.required(id, "id", "rt")?,
+            cancelled: self.required(cancelled, "c", "rt")?,
+            reference_trip_stop: self.required(reference_trip_stop, "ea", "rt")?,
+            reference_trip: self.required(reference_trip, "rtl", "rt")?,
+            sd: self.required(sd, "sd", "rt")?,
+        })
+    }
+
+    fn reference_trip_stop(
+        &mut self,
+        element: &BytesStart,
+        empty: bool,
+        name: &str,
+    ) -> Result<ReferenceTripStopCharacterization> {
+        let mut eva = None;
+        let mut index = None;
+        let mut stop_name = None;
+        let mut planned_time = None;
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"eva" => eva = Some(value.into_owned()),
+                b"i" => index = Some(self.number(&value)?),
+                b"n" => stop_name = Some(value.into_owned()),
+                b"pt" => planned_time = Some(value.into_owned()),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        self.skip(element, empty)?;
+
+        Ok(ReferenceTripStopCharacterization {
+            eva: self.required(eva, "eva", name)?,
+            index: self.required(index, "i", name)?,
+            name: self.required(stop_name, "n", name)?,
+            planned_time: self.required(planned_time, "pt", name)?,
+        })
+    }
+
+    fn connection(&mut self, element: &BytesStart, empty: bool) -> Result<Connection> {
+        let mut connection_status = None;
+        let mut eva = None;
+        let mut id = None;
+        let mut timestamp = None;
+        let mut reference = None;
+        let mut stop = None;
+
+        self.attributes(element, |key, value| {
+            match key {
+                b"cs" => connection_status = Some(self.connection_status(&value)?),
+                b"eva" => eva = Some(self.number(&value)?),
+                b"id" => id = Some(value.into_owned()),
+                b"ts" => timestamp = Some(value.into_owned()),
+                _ => {}
+            }
+            Ok(())
+        })?;
+
+        self.children(empty, |parser, child, empty| {
+            match child.name().as_ref() {
+                b"ref" => reference = Some(Box::new(parser.stop(&child, empty)?)),
+                b"s" => stop = Some(Box::new(parser.stop(&child, empty)?)),
+                _ => parser.skip(&child, empty)?,
+            }
+            Ok(())
+        })?;
+
+        Ok(Connection {
+            connection_status: self.required(connection_status, "cs", "conn")?,
+            eva,
+            id: self.required(id, "id", "conn")?,
+            reference,
+            s: self.required(stop, "s", "conn")?,
+            timestamp: self.required(timestamp, "ts", "conn")?,
+        })
+    }
+
+    fn number<T: std::str::FromStr>(&self, value: &str) -> Result<T> {
+        value
+            .parse()
+            .map_err(|_| self.error(&format!("invalid number `{value}`")))
+    }
+
+    fn unknown_variant<T>(&self, value: &str) -> Result<T> {
+        Err(self.error(&format!("unknown variant `{value}`")))
+    }
+
+    fn event_status(&self, value: &str) -> Result<EventStatus> {
+        match value {
+            "p" => Ok(EventStatus::Planned),
+            "c" => Ok(EventStatus::Cancelled),
+            "a" => Ok(EventStatus::Added),
+            _ => self.unknown_variant(value),
+        }
+    }
+
+    fn message_priority(&self, value: &str) -> Result<MessagePriority> {
+        match value {
+            "1" => Ok(MessagePriority::High),
+            "2" => Ok(MessagePriority::Medium),
+            "3" => Ok(MessagePriority::Low),
+            "4" => Ok(MessagePriority::Done),
+            _ => self.unknown_variant(value),
+        }
+    }
+
+    fn message_status(&self, value: &str) -> Result<MessageStatus> {
+        match value {
+            "h" => Ok(MessageStatus::HafasInformationManager),
+            "q" => Ok(MessageStatus::QualityChange),
+            "f" => Ok(MessageStatus::Free),
+            "d" => Ok(MessageStatus::CauseOfDelay),
+            "i" => Ok(MessageStatus::Ibis),
+            "u" => Ok(MessageStatus::UnassignedIbis),
+            "r" => Ok(MessageStatus::Disruption),
+            "c" => Ok(MessageStatus::Connection),
+            _ => self.unknown_variant(value),
+        }
+    }
+
+    fn distributor_type(&self, value: &str) -> Result<DistributorType> {
+        match value {
+            "s" => Ok(DistributorType::City),
+            "r" => Ok(DistributorType::Region),
+            "f" => Ok(DistributorType::LongDistance),
+            "x" => Ok(DistributorType::Other),
+            _ => self.unknown_variant(value),
+        }
+    }
+
+    fn trip_type(&self, value: &str) -> Result<TripType> {
+        match value {
+            "p" => Ok(TripType::P),
+            "e" => Ok(TripType::E),
+            "z" => Ok(TripType::Z),
+            "s" => Ok(TripType::S),
+            "h" => Ok(TripType::H),
+            "n" => Ok(TripType::N),
+            _ => self.unknown_variant(value),
+        }
+    }
+
+    fn relation_type(&self, value: &str) -> Result<ReplationType> {
+        match value {
+            "b" => Ok(ReplationType::Before),
+            "e" => Ok(ReplationType::End),
+            "c" => Ok(ReplationType::Between),
+            "s" => Ok(ReplationType::Start),
+            "a" => Ok(ReplationType::After),
+            _ => self.unknown_variant(value),
+        }
+    }
+
+    fn connection_status(&self, value: &str) -> Result<ConnectionStatus> {
+        match value {
+            "w" => Ok(ConnectionStatus::Waiting),
+            "n" => Ok(ConnectionStatus::Transition),
+            "a" => Ok(ConnectionStatus::Alternative),
+            _ => self.unknown_variant(value),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 /// A timetable is made of a set of TimetableStops and a potential Disruption
 pub struct TimeTable {
-    #[serde(rename = "station")]
+    #[serde(rename = "station", default)]
     /// Station name
     pub station_name: String,
     /// EVA station number
@@ -150,6 +150,7 @@ pub struct Message {
     pub priority: Option<MessagePriority>,
     #[serde(rename = "t")]
     pub message_status: MessageStatus,
+    #[serde(rename = "tl")]
     pub trip_label: Option<Vec<TripLabel>>,
     #[serde(rename = "dm")]
     pub distributor_messages: Option<Vec<DistributorMessage>>,
@@ -336,6 +337,7 @@ pub struct Connection {
     #[serde(rename = "ref")]
     pub reference: Option<Box<TimetableStop>>,
     pub s: Box<TimetableStop>,
+    #[serde(rename = "ts")]
     /// The time, in ten digit 'YYMMddHHmm' format, e.g. '1404011437' for 14:37 on April the 1st of 2014
     pub timestamp: String,
 }
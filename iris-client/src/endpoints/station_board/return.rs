@@ -1,18 +1,30 @@
 use std::collections::HashSet;
 
-use chrono::{DateTime, FixedOffset, Offset};
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
+pub mod connection;
+pub mod disruption;
+pub mod filter;
 pub mod message;
+pub mod reference;
 pub mod stop;
+pub mod wing;
 
+pub use connection::*;
+pub use disruption::*;
+pub use filter::*;
 pub use message::*;
+pub use reference::*;
 pub use stop::*;
 use utoipa::ToSchema;
+pub use wing::*;
 
 use crate::helpers::parse_iris_date;
+use crate::{IrisTransformError, IrisUnavailableStation};
 
-use super::response::{EventStatus, TimetableStop};
+use super::response::{EventStatus, TimeTable, TimetableStop};
 
 use wu_diff::*;
 
@@ -21,35 +33,185 @@ use wu_diff::*;
 pub struct IrisStationBoard {
     pub station_name: String,
     pub station_eva: String,
-    pub disruptions: Vec<Message>,
+    /// Disruptions that affect the whole station
+    pub disruptions: Vec<StationDisruption>,
     pub stops: Vec<StationBoardStop>,
+    /// Stops and messages that were skipped because IRIS returned invalid data for them
+    #[serde(default)]
+    pub warnings: Vec<IrisTransformError>,
+    /// Meta stations whose station board could not be fetched
+    #[serde(default)]
+    pub unavailable_stations: Vec<IrisUnavailableStation>,
 }
 
+impl IrisStationBoard {
+    /// Merges the station board of another station (e.G. a meta station of this station) into this station board.
+    ///
+    /// The stops keep the `station_eva` of the station they came from and are sorted by their planned time again, \
+    /// disruptions that both stations share are only kept once. \
+    /// Wings that are on the other station board are resolved afterwards.
+    pub fn merge(&mut self, other: IrisStationBoard) {
+        for disruption in other.disruptions {
+            if !self
+                .disruptions
+                .iter()
+                .any(|existing| existing.id == disruption.id)
+            {
+                self.disruptions.push(disruption);
+            }
+        }
+        self.stops.extend(other.stops);
+        self.stops.sort_by_key(StationBoardStop::planned_time);
+        self.warnings.extend(other.warnings);
+        self.unavailable_stations.extend(other.unavailable_stations);
+
+        resolve_wings(&mut self.stops);
+    }
+
+    /// Replaces the `matched_text` of all messages on the station board with the text in the specified language.
+    pub fn localize(&mut self, language: MessageLanguage) {
+        for disruption in &mut self.disruptions {
+            disruption.localize(language);
+        }
+
+        for message in self
+            .stops
+            .iter_mut()
+            .flat_map(|stop| stop.messages.iter_mut())
+        {
+            message.localize(language);
+        }
+    }
+}
+
+/// How invalid stops or messages are handled when transforming IRIS data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransformMode {
+    /// Fail the whole transformation on the first invalid stop or message
+    Strict,
+    /// Skip invalid stops and messages and collect them as warnings
+    #[default]
+    Lenient,
+}
+
+impl TransformMode {
+    fn handle<T>(
+        self,
+        result: Result<T, IrisTransformError>,
+        warnings: &mut Vec<IrisTransformError>,
+    ) -> Result<Option<T>, IrisTransformError> {
+        match (result, self) {
+            (Ok(value), _) => Ok(Some(value)),
+            (Err(err), TransformMode::Lenient) => {
+                warnings.push(err);
+                Ok(None)
+            }
+            (Err(err), TransformMode::Strict) => Err(err),
+        }
+    }
+}
+
+/// Combines the planned `timetables` of a station with its `realtime` information into an [`IrisStationBoard`].
+///
+/// Stops that only exist in the realtime data are added if they are between `start` and `end`.
+pub fn from_iris_timetables(
+    station_eva: &str,
+    timetables: Vec<TimeTable>,
+    realtime: TimeTable,
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    mode: TransformMode,
+) -> Result<IrisStationBoard, IrisTransformError> {
+    let mut warnings = Vec::new();
+    let mut stops = Vec::new();
+
+    for timetable in timetables {
+        for stop in timetable.stops {
+            let realtime = realtime
+                .stops
+                .iter()
+                .find(|realtime_stop| realtime_stop.id == stop.id);
+            let stop = from_iris_timetable(
+                station_eva,
+                &timetable.station_name,
+                stop,
+                realtime.map(|realtime| realtime.to_owned()),
+                mode,
+                &mut warnings,
+            );
+            if let Some(stop) = mode.handle(stop, &mut warnings)? {
+                stops.push(stop);
+            }
+        }
+    }
+
+    for stop in additional_realtime_stops(
+        station_eva,
+        &realtime,
+        &stops,
+        start,
+        end,
+        mode,
+        &mut warnings,
+    ) {
+        if let Some(stop) = mode.handle(stop, &mut warnings)? {
+            stops.push(stop);
+        }
+    }
+
+    stops.sort_by_key(StationBoardStop::planned_time);
+
+    resolve_wings(&mut stops);
+
+    let mut disruptions = Vec::new();
+
+    for message in realtime.disruptions {
+        if let Some(message) = mode.handle(message.try_into(), &mut warnings)? {
+            disruptions.push(message);
+        }
+    }
+
+    Ok(IrisStationBoard {
+        station_name: realtime.station_name,
+        station_eva: String::from(station_eva),
+        disruptions,
+        stops,
+        warnings,
+        unavailable_stations: vec![],
+    })
+}
+
+/// Combines a planned stop with its realtime information.
+///
+/// Invalid messages do not fail the stop, they are handled according to `mode` and added to `warnings`.
 pub fn from_iris_timetable(
     station_eva: &str,
     station_name: &str,
     stop: TimetableStop,
     realtime: Option<TimetableStop>,
-) -> StationBoardStop {
-    let mut messages: HashSet<Message> = HashSet::new();
+    mode: TransformMode,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Result<StationBoardStop, IrisTransformError> {
+    let trip_label =
+        stop.trip_label
+            .as_ref()
+            .ok_or_else(|| IrisTransformError::MissingTripLabel {
+                stop_id: stop.id.to_owned(),
+            })?;
 
-    if let Some(realtime) = &realtime {
-        if let Some(msgs) = &realtime.messages {
-            for message in msgs {
-                messages.insert(message.to_owned().into());
-            }
-            if let Some(departure) = &realtime.departure {
-                for message in &departure.messages {
-                    messages.insert(message.to_owned().into());
-                }
-            }
-            if let Some(arrival) = &realtime.arrival {
-                for message in &arrival.messages {
-                    messages.insert(message.to_owned().into());
-                }
-            }
-        }
-    }
+    let planned_dep_arr = stop
+        .departure
+        .as_ref()
+        .or(stop.arrival.as_ref())
+        .ok_or_else(|| IrisTransformError::MissingArrivalDeparture {
+            stop_id: stop.id.to_owned(),
+        })?;
+
+    let messages = realtime
+        .as_ref()
+        .map(|realtime| collect_messages(realtime, mode, warnings))
+        .transpose()?
+        .unwrap_or_default();
 
     let event_status = realtime
         .as_ref()
@@ -92,61 +254,18 @@ pub fn from_iris_timetable(
     if let Some(stops) = stop
         .arrival
         .as_ref()
-        .map(|arr| arr.planned_path.as_ref().unwrap())
+        .and_then(|arr| arr.planned_path.as_ref())
     {
-        let current_path: Option<Vec<&str>> = realtime
+        let current_path = realtime
             .as_ref()
-            .and_then(|realtime| {
-                realtime
-                    .arrival
-                    .as_ref()
-                    .map(|real_dep| real_dep.changed_path.as_ref())
-            })
-            .flatten()
-            .map(|path| path.split('|').collect());
-
-        if let Some(current_path) = current_path {
-            let old = stops.split('|').collect::<Vec<&str>>();
-
-            for diff in wu_diff::diff(&old, &current_path) {
-                match diff {
-                    DiffResult::Common(same) => {
-                        route.push(RouteStop {
-                            name: current_path[same.new_index.unwrap()].to_string(),
-                            cancelled: false,
-                            added: false,
-                        });
-                    }
-                    DiffResult::Added(add) => {
-                        let new_stop = current_path[add.new_index.unwrap()];
-                        if !new_stop.is_empty() {
-                            route.push(RouteStop {
-                                name: new_stop.to_string(),
-                                cancelled: false,
-                                added: true,
-                            });
-                        }
-                    }
-                    DiffResult::Removed(rem) => {
-                        route.push(RouteStop {
-                            name: old[rem.old_index.unwrap()].to_string(),
-                            cancelled: true,
-                            added: false,
-                        });
-                    }
-                }
-            }
-        } else {
-            for stop in stops.split('|') {
-                route.push(RouteStop {
-                    name: stop.to_string(),
-                    cancelled: false,
-                    added: false,
-                });
-            }
-        }
+            .and_then(|realtime| realtime.arrival.as_ref())
+            .and_then(|real_arr| real_arr.changed_path.as_deref());
+
+        route.extend(route_stops(stops, current_path));
     }
 
+    let mut route_changed = route.iter().any(|stop| stop.added || stop.cancelled);
+
     let cancelled = event_status == Some(EventStatus::Cancelled);
     let added = event_status == Some(EventStatus::Added);
 
@@ -159,166 +278,496 @@ pub fn from_iris_timetable(
     if let Some(stops) = stop
         .departure
         .as_ref()
-        .map(|dep| dep.planned_path.as_ref().unwrap())
+        .and_then(|dep| dep.planned_path.as_ref())
     {
-        let current_path: Option<Vec<&str>> = realtime
+        let current_path = realtime
             .as_ref()
-            .and_then(|realtime| {
+            .and_then(|realtime| realtime.departure.as_ref())
+            .and_then(|real_dep| real_dep.changed_path.as_deref());
+
+        let departure_route = route_stops(stops, current_path);
+
+        route_changed |= departure_route
+            .iter()
+            .any(|stop| stop.added || stop.cancelled);
+
+        route.extend(departure_route);
+    }
+
+    let arrival = stop
+        .arrival
+        .as_ref()
+        .map(|arrival| {
+            let (planned_time, real_time) = event_times(
+                &stop.id,
+                arrival,
                 realtime
-                    .departure
                     .as_ref()
-                    .map(|real_dep| real_dep.changed_path.as_ref())
+                    .and_then(|realtime| realtime.arrival.as_ref()),
+            )?;
+
+            Ok::<_, IrisTransformError>(StationBoardStopArrival {
+                planned_time,
+                real_time,
+                wings: split_wings(arrival.wings.as_deref()),
+                origin: route
+                    .first()
+                    .map(|stop| stop.name.to_owned())
+                    .unwrap_or_default(),
             })
-            .flatten()
-            .map(|path| path.split('|').collect());
-
-        if let Some(current_path) = current_path {
-            let old = stops.split('|').collect::<Vec<&str>>();
-
-            for diff in wu_diff::diff(&old, &current_path) {
-                match diff {
-                    DiffResult::Common(same) => {
-                        route.push(RouteStop {
-                            name: current_path[same.new_index.unwrap()].to_string(),
-                            cancelled: false,
-                            added: false,
-                        });
-                    }
-                    DiffResult::Added(add) => {
-                        let new_stop = current_path[add.new_index.unwrap()];
-                        if !new_stop.is_empty() {
-                            route.push(RouteStop {
-                                name: new_stop.to_string(),
-                                cancelled: false,
-                                added: true,
-                            });
-                        }
-                    }
-                    DiffResult::Removed(rem) => {
-                        route.push(RouteStop {
-                            name: old[rem.old_index.unwrap()].to_string(),
-                            cancelled: true,
-                            added: false,
-                        });
-                    }
-                }
-            }
-        } else {
-            for stop in stops.split('|') {
-                route.push(RouteStop {
-                    name: stop.to_string(),
-                    cancelled: false,
-                    added: false,
-                });
-            }
-        }
-    }
+        })
+        .transpose()?;
+
+    let departure = stop
+        .departure
+        .as_ref()
+        .map(|departure| {
+            let (planned_time, real_time) = event_times(
+                &stop.id,
+                departure,
+                realtime
+                    .as_ref()
+                    .and_then(|realtime| realtime.departure.as_ref()),
+            )?;
+
+            Ok::<_, IrisTransformError>(StationBoardStopDeparture {
+                planned_time,
+                real_time,
+                wings: split_wings(departure.wings.as_deref()),
+                direction: route
+                    .last()
+                    .map(|stop| stop.name.to_owned())
+                    .unwrap_or_default(),
+            })
+        })
+        .transpose()?;
+
+    let arrival_delay_minutes = arrival
+        .as_ref()
+        .and_then(|arrival| delay_minutes(arrival.planned_time, arrival.real_time));
+    let departure_delay_minutes = departure
+        .as_ref()
+        .and_then(|departure| delay_minutes(departure.planned_time, departure.real_time));
+
+    let realtime_dep_arr = realtime
+        .as_ref()
+        .and_then(|realtime| realtime.departure.as_ref().or(realtime.arrival.as_ref()));
+
+    let real_platform = realtime.as_ref().and_then(|realtime| {
+        realtime
+            .departure
+            .as_ref()
+            .and_then(|departure| departure.changed_platform.to_owned())
+            .or_else(|| {
+                realtime
+                    .arrival
+                    .as_ref()
+                    .and_then(|arrival| arrival.changed_platform.to_owned())
+            })
+    });
+
+    let cancellation_time = realtime
+        .as_ref()
+        .and_then(|realtime| {
+            realtime
+                .departure
+                .as_ref()
+                .and_then(|departure| departure.cancellation_time.as_deref())
+                .or_else(|| {
+                    realtime
+                        .arrival
+                        .as_ref()
+                        .and_then(|arrival| arrival.cancellation_time.as_deref())
+                })
+        })
+        .map(parse_date)
+        .transpose()?
+        .map(|time| time.fixed_offset());
 
-    StationBoardStop {
-        id: stop.id,
+    Ok(StationBoardStop {
+        id: stop.id.to_owned(),
         station_name: String::from(station_name),
         station_eva: String::from(station_eva),
-        messages: messages.into_iter().collect(),
+        messages,
         cancelled: event_status == Some(EventStatus::Cancelled),
         added: event_status == Some(EventStatus::Added),
         hidden: hidden.unwrap_or(false),
+        connections: realtime
+            .as_ref()
+            .map(|realtime| {
+                realtime
+                    .connection
+                    .iter()
+                    .cloned()
+                    .filter_map(from_iris_connection)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        reference_trip: realtime
+            .as_ref()
+            .and_then(|realtime| realtime.reference_trip_relation.to_owned())
+            .or_else(|| stop.reference_trip_relation.to_owned())
+            .map(|relation| relation.into()),
         replaces: realtime.as_ref().and_then(|realtime| {
             realtime.reference.as_ref().map(|reference| ReplacedTrain {
                 category: reference.trip_label.category.to_owned(),
                 number: reference.trip_label.train_number.to_owned(),
             })
         }),
-        arrival: stop.arrival.as_ref().map(|arrival| {
-            let plan_date = parse_iris_date(arrival.planned_time.as_ref().unwrap()).unwrap();
-            let plan_offset = plan_date.offset().fix();
-            let real_date = realtime
-                .as_ref()
-                .and_then(|realtime| {
-                    realtime
-                        .arrival
-                        .as_ref()
-                        .map(|departure| departure.changed_time.as_ref())
-                })
-                .flatten()
-                .map(|time| parse_iris_date(time).unwrap());
-            let real_offset = real_date
-                .as_ref()
-                .map(|date| date.offset().fix())
-                .unwrap_or_else(|| plan_offset);
-
-            StationBoardStopArrival {
-                planned_time: DateTime::<FixedOffset>::from_utc(plan_date.naive_utc(), plan_offset),
-                real_time: real_date
-                    .map(|date| DateTime::<FixedOffset>::from_utc(date.naive_utc(), real_offset)),
-                wings: arrival
-                    .wings
-                    .as_ref()
-                    .map(|wings| wings.split('|').map(|string| string.to_string()).collect())
-                    .unwrap_or_default(),
-                origin: route.first().unwrap().name.to_owned(),
-            }
+        arrival,
+        departure,
+        route,
+        planned_platform: planned_dep_arr.planned_platform.to_owned(),
+        platform_changed: real_platform.as_ref().is_some_and(|real_platform| {
+            Some(real_platform) != planned_dep_arr.planned_platform.as_ref()
         }),
-        departure: stop.departure.as_ref().map(|departure| {
-            let plan_date = parse_iris_date(departure.planned_time.as_ref().unwrap()).unwrap();
-            let plan_offset = plan_date.offset().fix();
-            let real_date = realtime
-                .as_ref()
-                .and_then(|realtime| {
-                    realtime
-                        .departure
-                        .as_ref()
-                        .map(|departure| departure.changed_time.as_ref())
-                })
-                .flatten()
-                .map(|time| parse_iris_date(time).unwrap());
-            let real_offset = real_date
-                .as_ref()
-                .map(|date| date.offset().fix())
-                .unwrap_or_else(|| plan_offset);
-
-            StationBoardStopDeparture {
-                planned_time: DateTime::<FixedOffset>::from_utc(plan_date.naive_utc(), plan_offset),
-                real_time: real_date
-                    .map(|date| DateTime::<FixedOffset>::from_utc(date.naive_utc(), real_offset)),
-                wings: departure
-                    .wings
-                    .as_ref()
-                    .map(|wings| wings.split('|').map(|string| string.to_string()).collect())
-                    .unwrap_or_default(),
-                direction: route.last().unwrap().name.to_owned(),
+        real_platform,
+        arrival_delay_minutes,
+        departure_delay_minutes,
+        route_changed,
+        cancellation_time,
+        distant_change: realtime_dep_arr.and_then(|dep_arr| dep_arr.distant_change),
+        line_indicator: planned_dep_arr
+            .line_indicator
+            .to_owned()
+            .unwrap_or_else(|| trip_label.train_number.to_owned()),
+        train_type: trip_label.category.to_owned(),
+        train_number: trip_label.train_number.to_owned(),
+    })
+}
+
+/// Builds the route stops from the planned path and marks stops that were added or removed in the changed path.
+fn route_stops(planned_path: &str, changed_path: Option<&str>) -> Vec<RouteStop> {
+    let old = planned_path.split('|').collect::<Vec<&str>>();
+
+    let Some(current_path) = changed_path else {
+        return old
+            .into_iter()
+            .map(|stop| RouteStop {
+                name: stop.to_string(),
+                cancelled: false,
+                added: false,
+            })
+            .collect();
+    };
+
+    let current_path = current_path.split('|').collect::<Vec<&str>>();
+
+    let mut route = Vec::new();
+
+    for diff in wu_diff::diff(&old, &current_path) {
+        match diff {
+            DiffResult::Common(same) => {
+                if let Some(index) = same.new_index {
+                    route.push(RouteStop {
+                        name: current_path[index].to_string(),
+                        cancelled: false,
+                        added: false,
+                    });
+                }
             }
-        }),
-        route,
-        planned_platform: stop
+            DiffResult::Added(add) => {
+                let new_stop = add.new_index.map(|index| current_path[index]);
+                if let Some(new_stop) = new_stop.filter(|stop| !stop.is_empty()) {
+                    route.push(RouteStop {
+                        name: new_stop.to_string(),
+                        cancelled: false,
+                        added: true,
+                    });
+                }
+            }
+            DiffResult::Removed(rem) => {
+                if let Some(index) = rem.old_index {
+                    route.push(RouteStop {
+                        name: old[index].to_string(),
+                        cancelled: true,
+                        added: false,
+                    });
+                }
+            }
+        }
+    }
+
+    route
+}
+
+/// Parses the planned and the (optional) changed time of an arrival or departure.
+fn event_times(
+    stop_id: &str,
+    planned: &super::response::ArrivalDeparture,
+    realtime: Option<&super::response::ArrivalDeparture>,
+) -> Result<(DateTime<FixedOffset>, Option<DateTime<FixedOffset>>), IrisTransformError> {
+    let planned_time =
+        planned
+            .planned_time
+            .as_deref()
+            .ok_or_else(|| IrisTransformError::MissingPlannedTime {
+                stop_id: stop_id.to_owned(),
+            })?;
+
+    let real_time = realtime
+        .and_then(|realtime| realtime.changed_time.as_deref())
+        .map(parse_date)
+        .transpose()?;
+
+    Ok((
+        parse_date(planned_time)?.fixed_offset(),
+        real_time.map(|time| time.fixed_offset()),
+    ))
+}
+
+/// The delay in whole minutes, `None` if there is no realtime information.
+fn delay_minutes(
+    planned_time: DateTime<FixedOffset>,
+    real_time: Option<DateTime<FixedOffset>>,
+) -> Option<i64> {
+    real_time.map(|real_time| (real_time - planned_time).num_minutes())
+}
+
+fn parse_date(date: &str) -> Result<DateTime<Tz>, IrisTransformError> {
+    parse_iris_date(date).ok_or_else(|| IrisTransformError::InvalidDate {
+        date: date.to_owned(),
+    })
+}
+
+/// Collects the messages of a realtime stop and its arrival and departure.
+///
+/// Invalid messages are handled according to `mode`, so in lenient mode they are skipped instead of failing the stop.
+fn collect_messages(
+    realtime: &TimetableStop,
+    mode: TransformMode,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Result<Vec<Message>, IrisTransformError> {
+    let mut messages: HashSet<Message> = HashSet::new();
+
+    let stop_messages = realtime.messages.iter().flatten();
+    let event_messages = realtime
+        .arrival
+        .iter()
+        .chain(realtime.departure.iter())
+        .flat_map(|dep_arr| dep_arr.messages.iter());
+
+    for message in stop_messages.chain(event_messages) {
+        if let Some(message) = mode.handle(message.to_owned().try_into(), warnings)? {
+            messages.insert(message);
+        }
+    }
+
+    Ok(messages.into_iter().collect())
+}
+
+/// Finds all stops that IRIS only knows about through its realtime data (e.g. extra or replacement trains) \
+/// and are not part of the already transformed `planned_stops`.
+///
+/// Only stops with an arrival or departure between `start` and `end` are returned, \
+/// warnings about invalid messages are only added for those stops.
+pub fn additional_realtime_stops(
+    station_eva: &str,
+    realtime: &TimeTable,
+    planned_stops: &[StationBoardStop],
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    mode: TransformMode,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Vec<Result<StationBoardStop, IrisTransformError>> {
+    let planned_ids = planned_stops
+        .iter()
+        .map(|stop| stop.id.as_str())
+        .collect::<HashSet<&str>>();
+
+    let mut stops = Vec::new();
+
+    for realtime_stop in realtime
+        .stops
+        .iter()
+        .filter(|realtime_stop| !planned_ids.contains(realtime_stop.id.as_str()))
+    {
+        let mut stop_warnings = Vec::new();
+
+        let stop = match from_iris_realtime(
+            station_eva,
+            &realtime.station_name,
+            realtime_stop.to_owned(),
+            mode,
+            &mut stop_warnings,
+        ) {
+            Ok(Some(stop)) => stop,
+            Ok(None) => continue,
+            Err(err) => {
+                stops.push(Err(err));
+                continue;
+            }
+        };
+
+        let time = stop
             .departure
             .as_ref()
-            .unwrap_or_else(|| stop.arrival.as_ref().unwrap())
-            .planned_platform
-            .to_owned(),
-        real_platform: realtime
+            .map(|departure| departure.planned_time)
+            .or_else(|| stop.arrival.as_ref().map(|arrival| arrival.planned_time));
+
+        if time.is_some_and(|time| time >= start && time <= end) {
+            warnings.extend(stop_warnings);
+            stops.push(Ok(stop));
+        }
+    }
+
+    stops
+}
+
+/// Transforms a stop that only exists in the realtime data of IRIS (it has no planned counterpart).
+///
+/// Since there is no planned data, the route is built from the changed path and the planned times are the changed times. \
+/// Returns `Ok(None)` if the stop has no trip label or neither an arrival nor a departure with a changed time. \
+/// Invalid messages are handled according to `mode` and added to `warnings`.
+pub fn from_iris_realtime(
+    station_eva: &str,
+    station_name: &str,
+    realtime: TimetableStop,
+    mode: TransformMode,
+    warnings: &mut Vec<IrisTransformError>,
+) -> Result<Option<StationBoardStop>, IrisTransformError> {
+    let Some(trip_label) = realtime.trip_label.as_ref() else {
+        return Ok(None);
+    };
+
+    let arrival_time = realtime
+        .arrival
+        .as_ref()
+        .and_then(|arrival| arrival.changed_time.as_deref())
+        .map(parse_date)
+        .transpose()?;
+    let departure_time = realtime
+        .departure
+        .as_ref()
+        .and_then(|departure| departure.changed_time.as_deref())
+        .map(parse_date)
+        .transpose()?;
+
+    let Some(dep_arr) = realtime
+        .departure
+        .as_ref()
+        .filter(|_| departure_time.is_some())
+        .or(realtime.arrival.as_ref().filter(|_| arrival_time.is_some()))
+    else {
+        return Ok(None);
+    };
+
+    let messages = collect_messages(&realtime, mode, warnings)?;
+
+    let cancelled = dep_arr.real_event_status == Some(EventStatus::Cancelled);
+
+    let mut route = Vec::new();
+
+    if arrival_time.is_some() {
+        if let Some(path) = realtime
+            .arrival
             .as_ref()
-            .and_then(|realtime| {
-                realtime
-                    .departure
-                    .as_ref()
-                    .or(stop.arrival.as_ref())
-                    .map(|dep_arr| dep_arr.planned_platform.to_owned())
-            })
-            .flatten(),
-        line_indicator: stop
+            .and_then(|arrival| arrival.changed_path.as_ref())
+        {
+            route.extend(added_route_stops(path));
+        }
+    }
+
+    route.push(RouteStop {
+        name: String::from(station_name),
+        cancelled,
+        added: true,
+    });
+
+    if departure_time.is_some() {
+        if let Some(path) = realtime
             .departure
-            .unwrap_or_else(|| stop.arrival.unwrap())
-            .line_indicator
-            .unwrap_or_else(|| stop.trip_label.as_ref().unwrap().train_number.to_owned()),
-        train_type: stop
-            .trip_label
             .as_ref()
-            .map(|trip_label| trip_label.category.to_owned())
-            .unwrap(),
-        train_number: stop
-            .trip_label
-            .as_ref()
-            .map(|trip_label| trip_label.train_number.to_owned())
-            .unwrap(),
+            .and_then(|departure| departure.changed_path.as_ref())
+        {
+            route.extend(added_route_stops(path));
+        }
     }
+
+    Ok(Some(StationBoardStop {
+        id: realtime.id.to_owned(),
+        station_eva: String::from(station_eva),
+        station_name: String::from(station_name),
+        messages,
+        arrival: arrival_time.and_then(|time| {
+            let time = time.fixed_offset();
+            realtime
+                .arrival
+                .as_ref()
+                .map(|arrival| StationBoardStopArrival {
+                    planned_time: time,
+                    real_time: Some(time),
+                    wings: split_wings(arrival.wings.as_deref()),
+                    origin: route
+                        .first()
+                        .map(|stop| stop.name.to_owned())
+                        .unwrap_or_default(),
+                })
+        }),
+        departure: departure_time.and_then(|time| {
+            let time = time.fixed_offset();
+            realtime
+                .departure
+                .as_ref()
+                .map(|departure| StationBoardStopDeparture {
+                    planned_time: time,
+                    real_time: Some(time),
+                    wings: split_wings(departure.wings.as_deref()),
+                    direction: route
+                        .last()
+                        .map(|stop| stop.name.to_owned())
+                        .unwrap_or_default(),
+                })
+        }),
+        planned_platform: dep_arr
+            .changed_platform
+            .to_owned()
+            .or_else(|| dep_arr.planned_platform.to_owned()),
+        real_platform: dep_arr.changed_platform.to_owned(),
+        platform_changed: false,
+        arrival_delay_minutes: arrival_time.map(|_| 0),
+        departure_delay_minutes: departure_time.map(|_| 0),
+        route_changed: false,
+        cancellation_time: dep_arr
+            .cancellation_time
+            .as_deref()
+            .map(parse_date)
+            .transpose()?
+            .map(|time| time.fixed_offset()),
+        distant_change: dep_arr.distant_change,
+        cancelled,
+        added: true,
+        hidden: dep_arr.hidden == Some(1),
+        train_type: trip_label.category.to_owned(),
+        train_number: trip_label.train_number.to_owned(),
+        line_indicator: dep_arr
+            .line_indicator
+            .to_owned()
+            .unwrap_or_else(|| trip_label.train_number.to_owned()),
+        route,
+        connections: realtime
+            .connection
+            .iter()
+            .cloned()
+            .filter_map(from_iris_connection)
+            .collect(),
+        reference_trip: realtime
+            .reference_trip_relation
+            .to_owned()
+            .map(|relation| relation.into()),
+        replaces: realtime.reference.as_ref().map(|reference| ReplacedTrain {
+            category: reference.trip_label.category.to_owned(),
+            number: reference.trip_label.train_number.to_owned(),
+        }),
+    }))
+}
+
+fn added_route_stops(path: &str) -> impl Iterator<Item = RouteStop> + '_ {
+    path.split('|')
+        .filter(|stop| !stop.is_empty())
+        .map(|stop| RouteStop {
+            name: stop.to_string(),
+            cancelled: false,
+            added: true,
+        })
 }
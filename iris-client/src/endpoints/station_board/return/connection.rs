@@ -0,0 +1,103 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::helpers::parse_iris_date;
+
+/// A connecting train at a stop (e.G. `ICE 123` waits for `RE 5`)
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StationBoardConnection {
+    pub id: String,
+    pub status: ConnectionStatus,
+    /// The id of the stop of the connecting train
+    pub stop_id: String,
+    #[schema(nullable)]
+    pub station_eva: Option<String>,
+    pub train_type: String,
+    pub train_number: String,
+    #[schema(nullable)]
+    pub line_indicator: Option<String>,
+    #[schema(nullable)]
+    pub planned_time: Option<DateTime<FixedOffset>>,
+    #[schema(nullable)]
+    pub real_time: Option<DateTime<FixedOffset>>,
+    #[schema(nullable)]
+    pub planned_platform: Option<String>,
+    #[schema(nullable)]
+    pub real_platform: Option<String>,
+    #[schema(nullable, value_type = String)]
+    pub timestamp: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ConnectionStatus {
+    /// This (regular) connection is waiting
+    Waiting,
+    /// This (regular) connection CANNOT wait
+    CannotWait,
+    /// This is an alternative (unplanned) connection that has been introduced as a replacement for one regular connection that cannot wait. \
+    /// Alternative connections are always waiting.
+    Alternative,
+}
+
+/// Transforms a connection IRIS returned for a stop.
+///
+/// Returns `None` if the connecting train has no trip label.
+pub fn from_iris_connection(
+    connection: crate::station_board::response::Connection,
+) -> Option<StationBoardConnection> {
+    let stop = connection.s;
+    let trip_label = stop.trip_label?;
+
+    // the connecting train usually arrives at the station, so its arrival is preferred
+    let dep_arr = stop.arrival.or(stop.departure);
+
+    let planned_time = dep_arr
+        .as_ref()
+        .and_then(|dep_arr| dep_arr.planned_time.as_deref())
+        .and_then(parse_iris_date)
+        .map(|date| date.fixed_offset());
+    let real_time = dep_arr
+        .as_ref()
+        .and_then(|dep_arr| dep_arr.changed_time.as_deref())
+        .and_then(parse_iris_date)
+        .map(|date| date.fixed_offset());
+
+    Some(StationBoardConnection {
+        id: connection.id,
+        status: connection.connection_status.into(),
+        stop_id: stop.id,
+        station_eva: connection
+            .eva
+            .map(|eva| eva.to_string())
+            .or(stop.eva_number),
+        train_type: trip_label.category,
+        train_number: trip_label.train_number,
+        line_indicator: dep_arr
+            .as_ref()
+            .and_then(|dep_arr| dep_arr.line_indicator.to_owned()),
+        planned_time,
+        real_time,
+        planned_platform: dep_arr
+            .as_ref()
+            .and_then(|dep_arr| dep_arr.planned_platform.to_owned()),
+        real_platform: dep_arr.and_then(|dep_arr| dep_arr.changed_platform),
+        timestamp: parse_iris_date(&connection.timestamp).map(|date| date.naive_local()),
+    })
+}
+
+impl From<crate::station_board::response::ConnectionStatus> for ConnectionStatus {
+    fn from(value: crate::station_board::response::ConnectionStatus) -> Self {
+        match value {
+            crate::station_board::response::ConnectionStatus::Waiting => ConnectionStatus::Waiting,
+            crate::station_board::response::ConnectionStatus::Transition => {
+                ConnectionStatus::CannotWait
+            }
+            crate::station_board::response::ConnectionStatus::Alternative => {
+                ConnectionStatus::Alternative
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{DateTime, FixedOffset, TimeZone};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::helpers::parse_iris_date;
+use crate::IrisTransformError;
+
+use super::message::lookup::{iris_message_lookup, iris_message_text};
+use super::{MessageLanguage, MessagePriority, MessageStatus};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// A disruption that affects a whole station instead of a single train, e.G. construction work or a blocked track.
+pub struct StationDisruption {
+    pub id: String,
+    pub timestamp: DateTime<FixedOffset>,
+    #[schema(nullable)]
+    pub code: Option<i32>,
+    #[schema(nullable)]
+    /// The matched text from the message code, if there is one
+    pub matched_text: Option<String>,
+    #[schema(nullable)]
+    pub category: Option<String>,
+    #[schema(nullable)]
+    pub external_category: Option<String>,
+    #[schema(nullable)]
+    /// The text of the disruption meant for passengers
+    pub external_text: Option<String>,
+    #[schema(nullable)]
+    /// A link to further information about the disruption
+    pub external_link: Option<String>,
+    #[schema(nullable)]
+    /// The text of the disruption meant for railway staff
+    pub internal_text: Option<String>,
+    #[schema(nullable)]
+    pub owner: Option<String>,
+    #[schema(nullable)]
+    pub valid_from: Option<DateTime<FixedOffset>>,
+    #[schema(nullable)]
+    pub valid_to: Option<DateTime<FixedOffset>>,
+    /// Whether the disruption was valid when it was transformed
+    pub active: bool,
+    pub message_status: MessageStatus,
+    #[schema(nullable)]
+    pub priority: Option<MessagePriority>,
+    /// The trains the disruption affects, empty if it affects the whole station
+    pub affected_trips: Vec<DisruptionTrip>,
+    /// Additional texts of the disruption by a specific distributor
+    pub distributor_messages: Vec<DisruptionDistributorMessage>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DisruptionTrip {
+    pub train_type: String,
+    pub train_number: String,
+    /// The short code of the operator of the train
+    pub owner: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DisruptionDistributorMessage {
+    #[schema(nullable)]
+    pub internal_text: Option<String>,
+    #[schema(nullable)]
+    pub distributor_name: Option<String>,
+    #[schema(nullable)]
+    pub distributor_type: Option<DisruptionDistributorType>,
+    #[schema(nullable)]
+    pub timestamp: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, ToSchema)]
+pub enum DisruptionDistributorType {
+    City,
+    Region,
+    LongDistance,
+    Other,
+}
+
+impl StationDisruption {
+    /// Whether the disruption is valid at the specified time.
+    ///
+    /// A disruption without a start or end is considered valid since or until forever.
+    pub fn is_active_at<T: TimeZone>(&self, time: &DateTime<T>) -> bool {
+        self.valid_from.is_none_or(|valid_from| valid_from <= *time)
+            && self.valid_to.is_none_or(|valid_to| valid_to >= *time)
+    }
+
+    /// Whether the disruption already ended at the specified time.
+    pub fn has_ended_at<T: TimeZone>(&self, time: &DateTime<T>) -> bool {
+        self.valid_to.is_some_and(|valid_to| valid_to < *time)
+    }
+
+    /// Replaces the `matched_text` with the text of the message code in the specified language.
+    pub fn localize(&mut self, language: MessageLanguage) {
+        if let Some(code) = self.code {
+            self.matched_text = iris_message_text(code, language).map(String::from);
+        }
+    }
+}
+
+impl TryFrom<crate::station_board::response::Message> for StationDisruption {
+    type Error = IrisTransformError;
+
+    fn try_from(value: crate::station_board::response::Message) -> Result<Self, Self::Error> {
+        let timestamp = parse_date(value.timestamp)?;
+        let valid_from = value.valid_from.map(parse_date).transpose()?;
+        let valid_to = value.valid_to.map(parse_date).transpose()?;
+
+        let mut disruption = Self {
+            id: value.id,
+            timestamp,
+            code: value.code,
+            matched_text: value.code.as_ref().and_then(iris_message_lookup),
+            category: value.category,
+            external_category: value.external_category,
+            external_text: value.external_text,
+            external_link: value.external_link,
+            internal_text: value.internal_text,
+            owner: value.owner,
+            valid_from,
+            valid_to,
+            active: false,
+            message_status: value.message_status.into(),
+            priority: value.priority.map(MessagePriority::from),
+            affected_trips: value
+                .trip_label
+                .unwrap_or_default()
+                .into_iter()
+                .map(|trip_label| DisruptionTrip {
+                    train_type: trip_label.category,
+                    train_number: trip_label.train_number,
+                    owner: trip_label.owner,
+                })
+                .collect(),
+            distributor_messages: value
+                .distributor_messages
+                .unwrap_or_default()
+                .into_iter()
+                .map(DisruptionDistributorMessage::try_from)
+                .collect::<Result<_, _>>()?,
+        };
+
+        disruption.active = disruption.is_active_at(&chrono::Utc::now());
+
+        Ok(disruption)
+    }
+}
+
+impl TryFrom<crate::station_board::response::DistributorMessage> for DisruptionDistributorMessage {
+    type Error = IrisTransformError;
+
+    fn try_from(
+        value: crate::station_board::response::DistributorMessage,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            internal_text: value.internal_text,
+            distributor_name: value.distributor_name,
+            distributor_type: value.distributor_type.map(DisruptionDistributorType::from),
+            timestamp: value.timestamp.map(parse_date).transpose()?,
+        })
+    }
+}
+
+impl From<crate::station_board::response::DistributorType> for DisruptionDistributorType {
+    fn from(value: crate::station_board::response::DistributorType) -> Self {
+        match value {
+            crate::station_board::response::DistributorType::City => {
+                DisruptionDistributorType::City
+            }
+            crate::station_board::response::DistributorType::Region => {
+                DisruptionDistributorType::Region
+            }
+            crate::station_board::response::DistributorType::LongDistance => {
+                DisruptionDistributorType::LongDistance
+            }
+            crate::station_board::response::DistributorType::Other => {
+                DisruptionDistributorType::Other
+            }
+        }
+    }
+}
+
+fn parse_date(date: String) -> Result<DateTime<FixedOffset>, IrisTransformError> {
+    parse_iris_date(&date)
+        .map(|date| date.fixed_offset())
+        .ok_or(IrisTransformError::InvalidDate { date })
+}
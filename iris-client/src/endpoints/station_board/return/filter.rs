@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::{wing::route_part, IrisStationBoard, StationBoardStop};
+
+/// Restricts a station board to stops that either arrive or depart at the station.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum StationBoardEventFilter {
+    Arrivals,
+    Departures,
+}
+
+/// Filters the stops of an [`IrisStationBoard`].
+///
+/// Every criterion that is set has to match for a stop to be kept, \
+/// list criteria match if any of their entries matches. All string comparisons ignore case.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StationBoardFilter {
+    /// Train types to keep, e.G. `ICE` or `S`
+    pub train_types: Vec<String>,
+    /// Lines to keep, either just the line indicator (`1`) or combined with the train type (`S1`, `S 1`)
+    pub lines: Vec<String>,
+    /// Platforms to keep, the changed platform is used if there is one
+    pub platforms: Vec<String>,
+    /// Only keep stops whose route passes through or ends at a station whose name contains this
+    ///
+    /// Departures are matched against the route after the station, arrivals against the route before it. \
+    /// Without an event filter, stops with a departure are matched as departures.
+    pub via: Option<String>,
+    pub events: Option<StationBoardEventFilter>,
+    /// Remove stops that IRIS marks as hidden
+    pub hide_hidden: bool,
+    pub hide_cancelled: bool,
+}
+
+impl StationBoardFilter {
+    /// Whether the stop satisfies all criteria of the filter.
+    pub fn matches(&self, stop: &StationBoardStop) -> bool {
+        if self.hide_hidden && stop.hidden {
+            return false;
+        }
+
+        if self.hide_cancelled && stop.cancelled {
+            return false;
+        }
+
+        match self.events {
+            Some(StationBoardEventFilter::Arrivals) if stop.arrival.is_none() => return false,
+            Some(StationBoardEventFilter::Departures) if stop.departure.is_none() => return false,
+            _ => {}
+        }
+
+        if !self.train_types.is_empty()
+            && !self
+                .train_types
+                .iter()
+                .any(|train_type| train_type.eq_ignore_ascii_case(&stop.train_type))
+        {
+            return false;
+        }
+
+        if !self.lines.is_empty() && !self.lines.iter().any(|line| matches_line(line, stop)) {
+            return false;
+        }
+
+        if !self.platforms.is_empty() {
+            let Some(platform) = stop
+                .real_platform
+                .as_ref()
+                .or(stop.planned_platform.as_ref())
+            else {
+                return false;
+            };
+
+            if !self
+                .platforms
+                .iter()
+                .any(|filter| filter.eq_ignore_ascii_case(platform))
+            {
+                return false;
+            }
+        }
+
+        if let Some(via) = &self.via {
+            let via = via.to_lowercase();
+
+            let departure = match self.events {
+                Some(StationBoardEventFilter::Arrivals) => false,
+                Some(StationBoardEventFilter::Departures) => true,
+                None => stop.departure.is_some(),
+            };
+
+            if !route_part(stop, departure)
+                .iter()
+                .any(|route_stop| route_stop.name.to_lowercase().contains(&via))
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+fn matches_line(line: &str, stop: &StationBoardStop) -> bool {
+    if stop.line_indicator.is_empty() {
+        return false;
+    }
+
+    let line = line.replace(' ', "");
+
+    line.eq_ignore_ascii_case(&stop.line_indicator)
+        || line.eq_ignore_ascii_case(&format!("{}{}", stop.train_type, stop.line_indicator))
+}
+
+impl IrisStationBoard {
+    /// Removes all stops from the station board that do not match the filter.
+    pub fn filter(&mut self, filter: &StationBoardFilter) {
+        self.stops.retain(|stop| filter.matches(stop));
+    }
+}
@@ -4,10 +4,13 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::helpers::parse_iris_date;
+use crate::IrisTransformError;
 
-use self::lookup::iris_message_lookup;
+use self::lookup::{iris_message_classification, iris_message_lookup, iris_message_text};
 
-mod lookup;
+pub use self::lookup::{MessageCodeCategory, MessageLanguage, MessageSeverity};
+
+pub mod lookup;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -21,6 +24,11 @@ pub struct Message {
     /// The matched text from the message code (e.G. `Schnee und Eis` when code is `95`)
     pub matched_text: Option<String>,
     #[schema(nullable)]
+    /// Whether the message code is about a delay cause, the quality of the train or general information
+    pub code_category: Option<MessageCodeCategory>,
+    #[schema(nullable)]
+    pub severity: Option<MessageSeverity>,
+    #[schema(nullable)]
     pub category: Option<String>,
     #[schema(nullable, value_type = String)]
     pub valid_from: Option<NaiveDateTime>,
@@ -31,6 +39,15 @@ pub struct Message {
     pub priority: Option<MessagePriority>,
 }
 
+impl Message {
+    /// Replaces the `matched_text` with the text of the message code in the specified language.
+    pub fn localize(&mut self, language: MessageLanguage) {
+        if let Some(code) = self.code {
+            self.matched_text = iris_message_text(code, language).map(String::from);
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, ToSchema)]
 pub enum MessageStatus {
     /// A HIM message (generated through the Hafas Information Manager)c
@@ -60,15 +77,25 @@ pub enum MessagePriority {
     Done,
 }
 
-impl From<crate::station_board::response::Message> for Message {
-    fn from(value: crate::station_board::response::Message) -> Self {
-        Self {
+impl TryFrom<crate::station_board::response::Message> for Message {
+    type Error = IrisTransformError;
+
+    fn try_from(value: crate::station_board::response::Message) -> Result<Self, Self::Error> {
+        let timestamp = parse_iris_date(&value.timestamp)
+            .map(|timestamp| timestamp.naive_local())
+            .ok_or(IrisTransformError::InvalidDate {
+                date: value.timestamp,
+            })?;
+
+        let classification = value.code.and_then(iris_message_classification);
+
+        Ok(Self {
             id: value.id,
-            timestamp: parse_iris_date(&value.timestamp)
-                .map(|timestamp| timestamp.naive_local())
-                .unwrap(),
+            timestamp,
             code: value.code,
             matched_text: value.code.as_ref().and_then(iris_message_lookup),
+            code_category: classification.map(|(category, _)| category),
+            severity: classification.map(|(_, severity)| severity),
             category: value.category,
             valid_from: value.valid_from.and_then(|valid_from| {
                 parse_iris_date(&valid_from).map(|valid_from| valid_from.naive_local())
@@ -78,7 +105,7 @@ impl From<crate::station_board::response::Message> for Message {
             }),
             message_status: value.message_status.into(),
             priority: value.priority.map(|priority| priority.into()),
-        }
+        })
     }
 }
 
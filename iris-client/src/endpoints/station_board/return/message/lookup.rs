@@ -2,112 +2,349 @@
  * This file was made possible by https://github.com/marudor/bahn.expert/blob/main/src/server/iris/messageLookup.ts
  */
 
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, Default, ToSchema)]
+/// The language message code texts are returned in
+pub enum MessageLanguage {
+    #[default]
+    #[serde(rename = "de")]
+    German,
+    #[serde(rename = "en")]
+    English,
+}
+
+impl MessageLanguage {
+    /// Matches a language tag like `en`, `en-GB` or `de-DE` against the supported languages.
+    pub fn from_language_tag(tag: &str) -> Option<Self> {
+        let primary = tag.trim().split(['-', '_']).next()?;
+
+        if primary.eq_ignore_ascii_case("de") {
+            Some(MessageLanguage::German)
+        } else if primary.eq_ignore_ascii_case("en") {
+            Some(MessageLanguage::English)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// What a message code is about
+pub enum MessageCodeCategory {
+    /// The cause of a delay or disruption
+    DelayCause,
+    /// Information about the comfort and equipment of the train, e.G. missing coaches or no Wi-Fi
+    Quality,
+    /// General information
+    Information,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, ToSchema)]
+#[serde(rename_all = "lowercase")]
+/// How much a message code affects the journey
+pub enum MessageSeverity {
+    Low,
+    Medium,
+    High,
+}
+
+/// Get the German text of a message code.
 pub fn iris_message_lookup(code: &i32) -> Option<String> {
+    iris_message_text(*code, MessageLanguage::German).map(String::from)
+}
+
+/// Get the text of a message code in the specified language.
+pub fn iris_message_text(code: i32, language: MessageLanguage) -> Option<&'static str> {
+    message_texts(code).map(|(german, english)| match language {
+        MessageLanguage::German => german,
+        MessageLanguage::English => english,
+    })
+}
+
+/// Get the category and severity of a message code.
+pub fn iris_message_classification(code: i32) -> Option<(MessageCodeCategory, MessageSeverity)> {
+    message_texts(code)?;
+
+    let classification = match code {
+        2 | 3 | 7 | 8 | 9 | 16 | 17 | 18 | 19 | 35 | 52 | 53 | 65 | 66 => {
+            (MessageCodeCategory::DelayCause, MessageSeverity::High)
+        }
+        1..=69 | 99 => (MessageCodeCategory::DelayCause, MessageSeverity::Medium),
+        77 | 79 | 82 | 85 | 90 | 91 | 93 | 95 | 96 | 97 => {
+            (MessageCodeCategory::Quality, MessageSeverity::Medium)
+        }
+        70..=98 => (MessageCodeCategory::Quality, MessageSeverity::Low),
+        _ => (MessageCodeCategory::Information, MessageSeverity::Low),
+    };
+
+    Some(classification)
+}
+
+/// The German and English text of a message code.
+fn message_texts(code: i32) -> Option<(&'static str, &'static str)> {
     let matched = match code {
-        1 => "Nähere Informationen in Kürze",
-        2 => "Polizeieinsatz",
-        3 => "Feuerwehreinsatz auf der Strecke",
-        4 => "Kurzfristiger Personalausfall",
-        5 => "Ärztliche Versorgung eines Fahrgastes",
-        6 => "Unbefugtes Ziehen der Notbremse",
-        7 => "Unbefugte Personen auf der Strecke",
-        8 => "Notarzteinsatz auf der Strecke",
-        9 => "Streikauswirkungen",
-        10 => "Tiere auf der Strecke",
-        11 => "Unwetter",
-        12 => "Warten auf ein verspätetes Schiff",
-        13 => "Pass- und Zollkontrolle",
-        14 => "Technischer Defekt am Bahnhof",
-        15 => "Beeinträchtigung durch Vandalismus",
-        16 => "Entschärfung einer Fliegerbombe",
-        17 => "Beschädigung einer Brücke",
-        18 => "Umgestürzter Baum auf der Strecke",
-        19 => "Unfall an einem Bahnübergang",
-        20 => "Tiere auf der Strecke",
-        21 => "Warten auf Anschlussreisende",
-        22 => "Witterungsbedingte Beeinträchtigungen",
-        23 => "Feuerwehreinsatz auf Bahngelände",
-        24 => "Verspätung aus dem Ausland",
-        25 => "Bereitstellung weiterer Wagen",
-        26 => "Abhängen von Wagen",
-        28 => "Gegenstände auf der Strecke",
-        29 => "Ersatzverkehr mit Bus ist eingerichtet",
-        31 => "Bauarbeiten",
-        32 => "Unterstützung beim Ein- und Ausstieg",
-        33 => "Reparatur an der Oberleitung",
-        34 => "Reparatur an einem Signal",
-        35 => "Streckensperrung",
-        36 => "Reparatur am Zug",
-        37 => "Reparatur am Wagen",
-        38 => "Reparatur an der Strecke",
-        39 => "Anhängen von zusätzlichen Wagen",
-        40 => "Defektes Stellwerk",
-        41 => "Technischer Defekt an einem Bahnübergang",
-        42 => "Vorübergehend verminderte Geschwindigkeit auf der Strecke",
-        43 => "Verspätung eines vorausfahrenden Zuges",
-        44 => "Warten auf einen entgegenkommenden Zug",
-        45 => "Überholung durch anderen Zug",
-        46 => "Warten auf freie Einfahrt",
-        47 => "Verspätete Bereitstellung",
-        48 => "Verspätung aus vorheriger Fahrt",
-        49 => "Kurzfristiger Personalausfall",
-        50 => "Kurzfristige Erkrankung von Personal",
-        51 => "Verspätetes Personal aus vorheriger Fahrt",
-        52 => "Streik",
-        53 => "Unwetterauswirkungen",
-        54 => "Verfügbarkeit der Gleise derzeit eingeschränkt",
-        55 => "Technische Störung an einem anderen Zug",
-        56 => "Warten auf Anschlussreisende",
-        57 => "Zusätzlicher Halt zum Ein- und Ausstieg",
-        58 => "Umleitung",
-        59 => "Schnee und Eis",
-        60 => "Witterungsbedingt verminderte Geschwindigkeit",
-        61 => "Defekte Tür",
-        62 => "Behobener technischer Defekt am Zug",
-        63 => "Technische Untersuchung am Zug",
-        64 => "Reparatur an der Weiche",
-        65 => "Erdrutsch",
-        66 => "Hochwasser",
-        67 => "Behördliche Maßnahme",
-        68 => "Hohes Fahrgastaufkommen verlängert Ein- und Ausstieg",
-        69 => "Zug verkehrt mit verminderter Geschwindigkeit",
-        70 => "WLAN nicht verfügbar",
-        71 => "WLAN in einem/mehreren Wagen nicht verfügbar",
-        72 => "Info-/Entertainment nicht verfügbar",
-        73 => "Mehrzweckabteil vorne",
-        74 => "Mehrzweckabteil hinten",
-        75 => "1. Klasse vorne",
-        76 => "1. Klasse hinten",
-        77 => "1. Klasse fehlt",
-        79 => "Mehrzweckabteil fehlt",
-        80 => "Abweichende Wagenreihung",
-        82 => "Mehrere Wagen fehlen",
-        83 => "Defekte fahrzeuggebundene Einstiegshilfe",
-        84 => "Zug verkehrt richtig gereiht", // r 80 82 83 85,
-        85 => "Ein Wagen fehlt",
-        86 => "Keine Reservierungsanzeige",
-        87 => "Einzelne Wagen ohne Reservierungsanzeige",
-        88 => "Keine Qualitätsmängel", // r 80 82 83 85 86 87 90 91 92 93 96 97 98,
-        89 => "Reservierungen sind wieder vorhanden",
-        90 => "Kein Bordrestaurant/Bordbistro",
-        91 => "Fahrradmitnahme nicht möglich",
-        92 => "Eingeschränkte Fahrradbeförderung",
-        93 => "Behindertengerechte Einrichtung fehlt",
-        94 => "Ersatzbewirtschaftung",
-        95 => "Universal-WC fehlt",
-        96 => "Der Zug ist stark überbesetzt", // r 97,
-        97 => "Der Zug ist überbesetzt",       // r 96,
-        98 => "Sonstige Qualitätsmängel",
-        99 => "Verzögerungen im Betriebsablauf",
-        900 => "Anschlussbus wartet (?)",
-        1000 => "Kundentext",
-        1001 => "Keine Zusatzhinweise",
-        _ => "",
+        1 => (
+            "Nähere Informationen in Kürze",
+            "Further information coming soon",
+        ),
+        2 => ("Polizeieinsatz", "Police operation"),
+        3 => (
+            "Feuerwehreinsatz auf der Strecke",
+            "Fire brigade operation on the line",
+        ),
+        4 => (
+            "Kurzfristiger Personalausfall",
+            "Short-notice staff shortage",
+        ),
+        5 => (
+            "Ärztliche Versorgung eines Fahrgastes",
+            "Medical care of a passenger",
+        ),
+        6 => (
+            "Unbefugtes Ziehen der Notbremse",
+            "Unauthorised use of the emergency brake",
+        ),
+        7 => (
+            "Unbefugte Personen auf der Strecke",
+            "Unauthorised persons on the line",
+        ),
+        8 => (
+            "Notarzteinsatz auf der Strecke",
+            "Emergency doctor operation on the line",
+        ),
+        9 => ("Streikauswirkungen", "Effects of a strike"),
+        10 => ("Tiere auf der Strecke", "Animals on the line"),
+        11 => ("Unwetter", "Severe weather"),
+        12 => (
+            "Warten auf ein verspätetes Schiff",
+            "Waiting for a delayed ship",
+        ),
+        13 => ("Pass- und Zollkontrolle", "Passport and customs control"),
+        14 => (
+            "Technischer Defekt am Bahnhof",
+            "Technical fault at the station",
+        ),
+        15 => (
+            "Beeinträchtigung durch Vandalismus",
+            "Impairment due to vandalism",
+        ),
+        16 => (
+            "Entschärfung einer Fliegerbombe",
+            "Defusing of a wartime bomb",
+        ),
+        17 => ("Beschädigung einer Brücke", "Damage to a bridge"),
+        18 => (
+            "Umgestürzter Baum auf der Strecke",
+            "Fallen tree on the line",
+        ),
+        19 => (
+            "Unfall an einem Bahnübergang",
+            "Accident at a level crossing",
+        ),
+        20 => ("Tiere auf der Strecke", "Animals on the line"),
+        21 => (
+            "Warten auf Anschlussreisende",
+            "Waiting for connecting passengers",
+        ),
+        22 => (
+            "Witterungsbedingte Beeinträchtigungen",
+            "Weather-related impairments",
+        ),
+        23 => (
+            "Feuerwehreinsatz auf Bahngelände",
+            "Fire brigade operation on railway premises",
+        ),
+        24 => ("Verspätung aus dem Ausland", "Delay from abroad"),
+        25 => (
+            "Bereitstellung weiterer Wagen",
+            "Provision of additional coaches",
+        ),
+        26 => ("Abhängen von Wagen", "Uncoupling of coaches"),
+        28 => ("Gegenstände auf der Strecke", "Objects on the line"),
+        29 => (
+            "Ersatzverkehr mit Bus ist eingerichtet",
+            "Replacement bus service has been set up",
+        ),
+        31 => ("Bauarbeiten", "Construction work"),
+        32 => (
+            "Unterstützung beim Ein- und Ausstieg",
+            "Assistance with boarding and alighting",
+        ),
+        33 => (
+            "Reparatur an der Oberleitung",
+            "Repair of the overhead line",
+        ),
+        34 => ("Reparatur an einem Signal", "Repair of a signal"),
+        35 => ("Streckensperrung", "Line closure"),
+        36 => ("Reparatur am Zug", "Repair of the train"),
+        37 => ("Reparatur am Wagen", "Repair of a coach"),
+        38 => ("Reparatur an der Strecke", "Repair of the line"),
+        39 => (
+            "Anhängen von zusätzlichen Wagen",
+            "Coupling of additional coaches",
+        ),
+        40 => ("Defektes Stellwerk", "Defective signal box"),
+        41 => (
+            "Technischer Defekt an einem Bahnübergang",
+            "Technical fault at a level crossing",
+        ),
+        42 => (
+            "Vorübergehend verminderte Geschwindigkeit auf der Strecke",
+            "Temporarily reduced speed on the line",
+        ),
+        43 => (
+            "Verspätung eines vorausfahrenden Zuges",
+            "Delay of a preceding train",
+        ),
+        44 => (
+            "Warten auf einen entgegenkommenden Zug",
+            "Waiting for an oncoming train",
+        ),
+        45 => (
+            "Überholung durch anderen Zug",
+            "Overtaking by another train",
+        ),
+        46 => ("Warten auf freie Einfahrt", "Waiting for clear entry"),
+        47 => (
+            "Verspätete Bereitstellung",
+            "Delayed provision of the train",
+        ),
+        48 => (
+            "Verspätung aus vorheriger Fahrt",
+            "Delay from a previous journey",
+        ),
+        49 => (
+            "Kurzfristiger Personalausfall",
+            "Short-notice staff shortage",
+        ),
+        50 => (
+            "Kurzfristige Erkrankung von Personal",
+            "Short-notice illness of staff",
+        ),
+        51 => (
+            "Verspätetes Personal aus vorheriger Fahrt",
+            "Delayed staff from a previous journey",
+        ),
+        52 => ("Streik", "Strike"),
+        53 => ("Unwetterauswirkungen", "Effects of severe weather"),
+        54 => (
+            "Verfügbarkeit der Gleise derzeit eingeschränkt",
+            "Track availability currently restricted",
+        ),
+        55 => (
+            "Technische Störung an einem anderen Zug",
+            "Technical fault on another train",
+        ),
+        56 => (
+            "Warten auf Anschlussreisende",
+            "Waiting for connecting passengers",
+        ),
+        57 => (
+            "Zusätzlicher Halt zum Ein- und Ausstieg",
+            "Additional stop for boarding and alighting",
+        ),
+        58 => ("Umleitung", "Diversion"),
+        59 => ("Schnee und Eis", "Snow and ice"),
+        60 => (
+            "Witterungsbedingt verminderte Geschwindigkeit",
+            "Reduced speed due to weather",
+        ),
+        61 => ("Defekte Tür", "Defective door"),
+        62 => (
+            "Behobener technischer Defekt am Zug",
+            "Rectified technical fault on the train",
+        ),
+        63 => (
+            "Technische Untersuchung am Zug",
+            "Technical inspection of the train",
+        ),
+        64 => ("Reparatur an der Weiche", "Repair of a switch"),
+        65 => ("Erdrutsch", "Landslide"),
+        66 => ("Hochwasser", "Flooding"),
+        67 => ("Behördliche Maßnahme", "Official order"),
+        68 => (
+            "Hohes Fahrgastaufkommen verlängert Ein- und Ausstieg",
+            "High passenger volume prolongs boarding and alighting",
+        ),
+        69 => (
+            "Zug verkehrt mit verminderter Geschwindigkeit",
+            "Train is running at reduced speed",
+        ),
+        70 => ("WLAN nicht verfügbar", "Wi-Fi not available"),
+        71 => (
+            "WLAN in einem/mehreren Wagen nicht verfügbar",
+            "Wi-Fi not available in one or more coaches",
+        ),
+        72 => (
+            "Info-/Entertainment nicht verfügbar",
+            "Info/entertainment not available",
+        ),
+        73 => (
+            "Mehrzweckabteil vorne",
+            "Multi-purpose compartment at the front",
+        ),
+        74 => (
+            "Mehrzweckabteil hinten",
+            "Multi-purpose compartment at the rear",
+        ),
+        75 => ("1. Klasse vorne", "1st class at the front"),
+        76 => ("1. Klasse hinten", "1st class at the rear"),
+        77 => ("1. Klasse fehlt", "1st class missing"),
+        79 => ("Mehrzweckabteil fehlt", "Multi-purpose compartment missing"),
+        80 => ("Abweichende Wagenreihung", "Different coach order"),
+        82 => ("Mehrere Wagen fehlen", "Several coaches missing"),
+        83 => (
+            "Defekte fahrzeuggebundene Einstiegshilfe",
+            "Defective on-board boarding aid",
+        ),
+        84 => (
+            "Zug verkehrt richtig gereiht",
+            "Train is running in the correct coach order",
+        ), // r 80 82 83 85,
+        85 => ("Ein Wagen fehlt", "One coach missing"),
+        86 => ("Keine Reservierungsanzeige", "No seat reservation display"),
+        87 => (
+            "Einzelne Wagen ohne Reservierungsanzeige",
+            "Individual coaches without seat reservation display",
+        ),
+        88 => ("Keine Qualitätsmängel", "No quality defects"), // r 80 82 83 85 86 87 90 91 92 93 96 97 98,
+        89 => (
+            "Reservierungen sind wieder vorhanden",
+            "Reservations are available again",
+        ),
+        90 => (
+            "Kein Bordrestaurant/Bordbistro",
+            "No on-board restaurant/bistro",
+        ),
+        91 => (
+            "Fahrradmitnahme nicht möglich",
+            "Bicycles cannot be taken along",
+        ),
+        92 => (
+            "Eingeschränkte Fahrradbeförderung",
+            "Limited bicycle transport",
+        ),
+        93 => (
+            "Behindertengerechte Einrichtung fehlt",
+            "Accessible facilities missing",
+        ),
+        94 => ("Ersatzbewirtschaftung", "Substitute catering"),
+        95 => ("Universal-WC fehlt", "Accessible toilet missing"),
+        96 => (
+            "Der Zug ist stark überbesetzt",
+            "The train is heavily overcrowded",
+        ), // r 97,
+        97 => ("Der Zug ist überbesetzt", "The train is overcrowded"), // r 96,
+        98 => ("Sonstige Qualitätsmängel", "Other quality defects"),
+        99 => ("Verzögerungen im Betriebsablauf", "Delays in operations"),
+        900 => ("Anschlussbus wartet (?)", "Connecting bus is waiting (?)"),
+        1000 => ("Kundentext", "Customer text"),
+        1001 => ("Keine Zusatzhinweise", "No additional information"),
+        _ => return None,
     };
 
-    if !matched.is_empty() {
-        Some(String::from(matched))
-    } else {
-        None
-    }
+    Some(matched)
 }
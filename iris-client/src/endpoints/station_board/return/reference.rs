@@ -0,0 +1,89 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::helpers::parse_iris_date;
+
+/// A reference trip is another trip that is related to a stop, e.G. a replacement service \
+/// that replaces, continues or shares (parts of) the path of the train at this stop.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceTrip {
+    /// The id of the reference trip, made of the daily trip id and the planned departure date (`YYMMddHHmm`) \
+    /// of the referenced trip from its start station
+    pub id: String,
+    pub cancelled: bool,
+    pub category: String,
+    pub number: String,
+    pub start: ReferenceTripStop,
+    pub end: ReferenceTripStop,
+    /// How the reference trip is related to this stop
+    pub relation: ReferenceTripRelation,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceTripStop {
+    pub eva: String,
+    pub name: String,
+    /// The index of the stop in the referenced trip
+    pub index: i32,
+    #[schema(nullable)]
+    pub planned_time: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum ReferenceTripRelation {
+    /// The reference trip ends before this stop
+    Before,
+    /// The reference trip ends at this stop
+    End,
+    /// This stop is between the start and the end of the reference trip
+    Between,
+    /// The reference trip starts at this stop
+    Start,
+    /// The reference trip starts after this stop
+    After,
+}
+
+impl From<crate::station_board::response::ReferenceTripRelation> for ReferenceTrip {
+    fn from(value: crate::station_board::response::ReferenceTripRelation) -> Self {
+        let trip = value.real_trip;
+
+        Self {
+            id: trip.id,
+            cancelled: trip.cancelled,
+            category: trip.reference_trip.category,
+            number: trip.reference_trip.number,
+            start: trip.sd.into(),
+            end: trip.reference_trip_stop.into(),
+            relation: value.relation.into(),
+        }
+    }
+}
+
+impl From<crate::station_board::response::ReferenceTripStopCharacterization> for ReferenceTripStop {
+    fn from(value: crate::station_board::response::ReferenceTripStopCharacterization) -> Self {
+        Self {
+            eva: value.eva,
+            name: value.name,
+            index: value.index,
+            planned_time: parse_iris_date(&value.planned_time).map(|date| date.fixed_offset()),
+        }
+    }
+}
+
+impl From<crate::station_board::response::ReplationType> for ReferenceTripRelation {
+    fn from(value: crate::station_board::response::ReplationType) -> Self {
+        match value {
+            crate::station_board::response::ReplationType::Before => ReferenceTripRelation::Before,
+            crate::station_board::response::ReplationType::End => ReferenceTripRelation::End,
+            crate::station_board::response::ReplationType::Between => {
+                ReferenceTripRelation::Between
+            }
+            crate::station_board::response::ReplationType::Start => ReferenceTripRelation::Start,
+            crate::station_board::response::ReplationType::After => ReferenceTripRelation::After,
+        }
+    }
+}
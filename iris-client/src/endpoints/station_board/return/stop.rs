@@ -2,7 +2,7 @@ use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use super::Message;
+use super::{Message, ReferenceTrip, StationBoardConnection, Wing};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -18,7 +18,24 @@ pub struct StationBoardStop {
     #[schema(nullable)]
     pub planned_platform: Option<String>,
     #[schema(nullable)]
+    /// The changed platform, only set if IRIS reported a platform change
     pub real_platform: Option<String>,
+    /// Whether the train departs or arrives at a different platform than planned
+    pub platform_changed: bool,
+    #[schema(nullable)]
+    /// `None` if there is no realtime information for the arrival
+    pub arrival_delay_minutes: Option<i64>,
+    #[schema(nullable)]
+    /// `None` if there is no realtime information for the departure
+    pub departure_delay_minutes: Option<i64>,
+    /// Whether stops were added to or removed from the planned route
+    pub route_changed: bool,
+    #[schema(nullable)]
+    /// When the cancellation of this stop was created
+    pub cancellation_time: Option<DateTime<FixedOffset>>,
+    #[schema(nullable)]
+    /// The distant change code reported by IRIS
+    pub distant_change: Option<i32>,
     pub cancelled: bool,
     pub added: bool,
     pub hidden: bool,
@@ -26,8 +43,23 @@ pub struct StationBoardStop {
     pub train_number: String,
     pub line_indicator: String,
     pub route: Vec<RouteStop>,
+    /// Connecting trains at this stop and whether they are waiting
+    pub connections: Vec<StationBoardConnection>,
     #[schema(nullable)]
     pub replaces: Option<ReplacedTrain>,
+    /// A trip that replaces, continues or shares its path with this train
+    #[schema(nullable)]
+    pub reference_trip: Option<ReferenceTrip>,
+}
+
+impl StationBoardStop {
+    /// The planned departure time of the stop, or its planned arrival time if it has no departure.
+    pub fn planned_time(&self) -> Option<DateTime<FixedOffset>> {
+        self.departure
+            .as_ref()
+            .map(|departure| departure.planned_time)
+            .or_else(|| self.arrival.as_ref().map(|arrival| arrival.planned_time))
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
@@ -49,7 +81,7 @@ pub struct StationBoardStopArrival {
     pub planned_time: DateTime<FixedOffset>,
     #[schema(nullable)]
     pub real_time: Option<DateTime<FixedOffset>>,
-    pub wings: Vec<String>,
+    pub wings: Vec<Wing>,
     pub origin: String,
 }
 
@@ -59,6 +91,6 @@ pub struct StationBoardStopDeparture {
     pub planned_time: DateTime<FixedOffset>,
     #[schema(nullable)]
     pub real_time: Option<DateTime<FixedOffset>>,
-    pub wings: Vec<String>,
+    pub wings: Vec<Wing>,
     pub direction: String,
 }
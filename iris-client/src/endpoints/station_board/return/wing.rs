@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::helpers::{is_stop_of_trip, trip_id};
+
+use super::{RouteStop, StationBoardStop};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+/// Another train that is coupled to this train for a part of its route, e.G. ICE 11 for ICE 1 between Berlin and Hamm
+pub struct Wing {
+    /// The trip id of the wing as reported by IRIS
+    pub trip_id: String,
+    /// The id of the stop of the wing on the same station board, if it is on the station board
+    #[schema(nullable)]
+    pub stop_id: Option<String>,
+    #[schema(nullable)]
+    pub train_type: Option<String>,
+    #[schema(nullable)]
+    pub train_number: Option<String>,
+    #[schema(nullable)]
+    pub line_indicator: Option<String>,
+    /// The destination of the wing for departures, its origin for arrivals
+    #[schema(nullable)]
+    pub terminus: Option<String>,
+    #[schema(nullable)]
+    pub position: Option<WingPosition>,
+    /// The station at which the wing splits from (for departures) or joins (for arrivals) this train
+    #[schema(nullable)]
+    pub split_join_station: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Copy, ToSchema)]
+/// The position of a wing in the coupled train.
+///
+/// IRIS lists the wings at the leading part of the train, \
+/// so if only one of the trains lists the other, it is considered the front part and the listed wing the rear part. \
+/// If both trains list each other, IRIS does not tell which one is in front and the position is `None`.
+pub enum WingPosition {
+    Front,
+    Rear,
+}
+
+impl Wing {
+    fn unresolved(trip_id: &str) -> Self {
+        Self {
+            trip_id: trip_id.to_owned(),
+            stop_id: None,
+            train_type: None,
+            train_number: None,
+            line_indicator: None,
+            terminus: None,
+            position: None,
+            split_join_station: None,
+        }
+    }
+}
+
+/// Parses the pipe separated `wings` attribute of IRIS into unresolved [`Wing`]s.
+pub(crate) fn split_wings(wings: Option<&str>) -> Vec<Wing> {
+    wings
+        .map(|wings| {
+            wings
+                .split('|')
+                .filter(|trip_id| !trip_id.is_empty())
+                .map(Wing::unresolved)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Resolves the wings of all `stops` against the other stops of the same station board.
+///
+/// Fills in train, terminus and split or join station of every wing that is on the station board \
+/// and links the wing back to the train that listed it, if IRIS did not already do so.
+///
+/// Wings that are already resolved are kept as they are, \
+/// so this can be called again after stops of other stations were added (e.G. when merging meta stations).
+pub fn resolve_wings(stops: &mut [StationBoardStop]) {
+    let mut back_links = Vec::new();
+
+    for index in 0..stops.len() {
+        for departure in [true, false] {
+            let wing_count = wings(&stops[index], departure).map_or(0, |wings| wings.len());
+
+            for wing_index in 0..wing_count {
+                let Some(trip_id) = wings(&stops[index], departure)
+                    .map(|wings| &wings[wing_index])
+                    .filter(|wing| wing.stop_id.is_none())
+                    .map(|wing| wing.trip_id.to_owned())
+                else {
+                    continue;
+                };
+
+                let Some(wing_stop) = stops
+                    .iter()
+                    .position(|stop| is_stop_of_trip(&stop.id, &trip_id))
+                else {
+                    continue;
+                };
+
+                if wing_stop == index {
+                    continue;
+                }
+
+                let listed_back = wings(&stops[wing_stop], departure).is_some_and(|wings| {
+                    wings
+                        .iter()
+                        .any(|wing| is_stop_of_trip(&stops[index].id, &wing.trip_id))
+                });
+
+                // When both trains list each other, there is nothing to tell which one is in front
+                let position = (!listed_back).then_some(WingPosition::Rear);
+
+                let wing = resolved_wing(
+                    &stops[index],
+                    &stops[wing_stop],
+                    trip_id,
+                    departure,
+                    position,
+                );
+
+                if let Some(wings) = wings_mut(&mut stops[index], departure) {
+                    wings[wing_index] = wing;
+                }
+
+                if !listed_back {
+                    back_links.push((wing_stop, index, departure));
+                }
+            }
+        }
+    }
+
+    for (index, front, departure) in back_links {
+        let trip_id = trip_id(&stops[front].id).to_owned();
+
+        if wings(&stops[index], departure).is_none() {
+            continue;
+        }
+
+        let wing = resolved_wing(
+            &stops[index],
+            &stops[front],
+            trip_id,
+            departure,
+            Some(WingPosition::Front),
+        );
+
+        if let Some(wings) = wings_mut(&mut stops[index], departure) {
+            if !wings
+                .iter()
+                .any(|existing| existing.trip_id == wing.trip_id)
+            {
+                wings.push(wing);
+            }
+        }
+    }
+}
+
+fn resolved_wing(
+    stop: &StationBoardStop,
+    wing_stop: &StationBoardStop,
+    trip_id: String,
+    departure: bool,
+    position: Option<WingPosition>,
+) -> Wing {
+    let terminus = if departure {
+        wing_stop
+            .departure
+            .as_ref()
+            .map(|departure| departure.direction.to_owned())
+    } else {
+        wing_stop
+            .arrival
+            .as_ref()
+            .map(|arrival| arrival.origin.to_owned())
+    };
+
+    Wing {
+        trip_id,
+        stop_id: Some(wing_stop.id.to_owned()),
+        train_type: Some(wing_stop.train_type.to_owned()),
+        train_number: Some(wing_stop.train_number.to_owned()),
+        line_indicator: Some(wing_stop.line_indicator.to_owned()),
+        terminus,
+        position,
+        split_join_station: Some(split_join_station(stop, wing_stop, departure)),
+    }
+}
+
+/// For departures the last station both trains still share after this station, \
+/// for arrivals the first station both trains share before this station.
+fn split_join_station(
+    stop: &StationBoardStop,
+    wing_stop: &StationBoardStop,
+    departure: bool,
+) -> String {
+    let route = route_part(stop, departure);
+    let wing_route = route_part(wing_stop, departure);
+
+    let shared = if departure {
+        route
+            .iter()
+            .zip(wing_route.iter())
+            .take_while(|(stop, wing_stop)| stop.name == wing_stop.name)
+            .last()
+    } else {
+        route
+            .iter()
+            .rev()
+            .zip(wing_route.iter().rev())
+            .take_while(|(stop, wing_stop)| stop.name == wing_stop.name)
+            .last()
+    };
+
+    shared
+        .map(|(stop, _)| stop.name.to_owned())
+        .unwrap_or_else(|| stop.station_name.to_owned())
+}
+
+/// The part of the route after (for departures) or before (for arrivals) the station of the stop.
+pub(crate) fn route_part(stop: &StationBoardStop, departure: bool) -> &[RouteStop] {
+    let Some(current) = stop
+        .route
+        .iter()
+        .position(|route_stop| route_stop.name == stop.station_name)
+    else {
+        return &[];
+    };
+
+    if departure {
+        &stop.route[current + 1..]
+    } else {
+        &stop.route[..current]
+    }
+}
+
+fn wings(stop: &StationBoardStop, departure: bool) -> Option<&Vec<Wing>> {
+    if departure {
+        stop.departure.as_ref().map(|departure| &departure.wings)
+    } else {
+        stop.arrival.as_ref().map(|arrival| &arrival.wings)
+    }
+}
+
+fn wings_mut(stop: &mut StationBoardStop, departure: bool) -> Option<&mut Vec<Wing>> {
+    if departure {
+        stop.departure
+            .as_mut()
+            .map(|departure| &mut departure.wings)
+    } else {
+        stop.arrival.as_mut().map(|arrival| &mut arrival.wings)
+    }
+}
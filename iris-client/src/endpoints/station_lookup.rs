@@ -0,0 +1,56 @@
+pub mod response;
+mod r#return;
+
+pub use r#return::*;
+
+use response::*;
+
+use crate::{IrisClient, IrisError, IrisOrRequestError};
+
+impl IrisClient {
+    /// Looks up stations in the IRIS station directory.
+    ///
+    /// Takes a pattern which can either be the name of the station e.G. `Frankfurt(Main)Hbf`, \
+    /// its eva number e.G. `8000105` or its DS100 code e.G. `FF`.
+    ///
+    /// Returns an empty list if IRIS knows no station matching the pattern.
+    pub async fn station_lookup(
+        &self,
+        pattern: &str,
+    ) -> Result<Vec<IrisStation>, IrisOrRequestError> {
+        let _permit = self.semaphore.acquire().await;
+
+        let response = self
+            .client
+            .get(format!(
+                "{}/iris-tts/timetable/station/{}",
+                self.base_url,
+                urlencoding::encode(pattern)
+            ))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(IrisOrRequestError::IrisError(IrisError));
+        }
+
+        let response: String = response.text().await?;
+
+        let response: StationsResponse = serde_xml_rs::from_str(&response)?;
+
+        Ok(response
+            .stations
+            .into_iter()
+            .map(IrisStation::from)
+            .collect())
+    }
+
+    /// Get the eva numbers of all meta stations of a station, not including the station itself.
+    ///
+    /// Takes the eva number of the station e.G. `8000105` for Frankfurt(Main)Hbf.
+    pub async fn meta_stations(&self, eva: &str) -> Result<Vec<String>, IrisOrRequestError> {
+        let stations = self.station_lookup(eva).await?;
+
+        Ok(meta_stations(eva, &stations))
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+/// The list of stations IRIS found for a lookup pattern
+pub struct StationsResponse {
+    #[serde(rename = "station", default)]
+    pub stations: Vec<Station>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+/// A station as it is known to IRIS
+pub struct Station {
+    /// Station name
+    pub name: String,
+    /// EVA station number
+    pub eva: String,
+    /// DS100 station code. Example 'FF' for Frankfurt(Main)Hbf
+    pub ds100: String,
+    /// List of meta station eva numbers separated by pipes
+    pub meta: Option<String>,
+    #[serde(rename = "p")]
+    /// List of platforms separated by pipes
+    pub platforms: Option<String>,
+    /// Whether the station belongs to the Deutsche Bahn, either 'true' or 'false'
+    pub db: Option<String>,
+    #[serde(rename = "creationts")]
+    /// The time the entry was created in the format 'yy-MM-dd HH:mm:ss.SSS'
+    pub creation_timestamp: Option<String>,
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::response;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IrisStation {
+    pub name: String,
+    pub eva: String,
+    pub ds100: String,
+    /// The eva numbers of stations that belong to the same meta station, e.G. the long distance and the S-Bahn platforms of a station
+    pub meta: Vec<String>,
+    pub platforms: Vec<String>,
+    pub db: bool,
+}
+
+impl From<response::Station> for IrisStation {
+    fn from(station: response::Station) -> Self {
+        Self {
+            name: station.name,
+            eva: station.eva,
+            ds100: station.ds100,
+            meta: split_list(station.meta),
+            platforms: split_list(station.platforms),
+            db: station.db.as_deref() == Some("true"),
+        }
+    }
+}
+
+/// Get the eva numbers of the meta stations of the station with the eva number `eva` from the result of a [`station_lookup`](crate::IrisClient::station_lookup).
+pub fn meta_stations(eva: &str, stations: &[IrisStation]) -> Vec<String> {
+    stations
+        .iter()
+        .find(|station| station.eva == eva)
+        .map(|station| {
+            station
+                .meta
+                .iter()
+                .filter(|meta_eva| meta_eva.as_str() != eva)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn split_list(list: Option<String>) -> Vec<String> {
+    list.map(|list| {
+        list.split('|')
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| entry.to_owned())
+            .collect()
+    })
+    .unwrap_or_default()
+}
@@ -1,16 +1,61 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, Debug, Error)]
 #[error("Iris returned an error.")]
 pub struct IrisError;
 
+/// An error that occurs when transforming the data IRIS returned into a station board.
+#[derive(Serialize, Deserialize, Debug, Error, PartialEq, Eq, Clone, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum IrisTransformError {
+    #[error("Stop {stop_id} has no trip label.")]
+    #[serde(rename_all = "camelCase")]
+    MissingTripLabel { stop_id: String },
+    #[error("Stop {stop_id} has neither an arrival nor a departure.")]
+    #[serde(rename_all = "camelCase")]
+    MissingArrivalDeparture { stop_id: String },
+    #[error("Stop {stop_id} has an arrival or departure without a planned time.")]
+    #[serde(rename_all = "camelCase")]
+    MissingPlannedTime { stop_id: String },
+    #[error("Iris returned an invalid date: {date}")]
+    InvalidDate { date: String },
+}
+
+/// A station whose data could not be fetched from IRIS, so it is missing from the result.
+#[derive(Serialize, Deserialize, Debug, Error, PartialEq, Eq, Clone, ToSchema)]
+#[error("The data of station {station} could not be fetched: {reason}")]
+#[serde(rename_all = "camelCase")]
+pub struct IrisUnavailableStation {
+    /// The eva number of the station, or its name if it was looked up by its name
+    pub station: String,
+    pub reason: String,
+}
+
+/// An error that occurs when the `quick-xml` parser can not read the XML IRIS returned.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("{message} at position {position}")]
+pub struct IrisXmlError {
+    pub message: String,
+    /// The byte offset in the document at which the error occurred
+    pub position: usize,
+}
+
 #[derive(Error, Debug)]
 pub enum IrisOrRequestError {
     #[error("Iris returned an error.")]
     IrisError(#[from] IrisError),
     #[error("Iris returned invalid/unrecognized XML: {0}")]
     InvalidXML(#[from] serde_xml_rs::Error),
+    #[error("Iris returned invalid/unrecognized XML: {0}")]
+    MalformedXML(#[from] IrisXmlError),
+    #[error("Iris returned data that could not be transformed: {0}")]
+    InvalidData(#[from] IrisTransformError),
+    #[error("{trip_id} is not a valid trip id.")]
+    InvalidTripId { trip_id: String },
+    #[error("Iris has no stop of trip {trip_id} at station {eva}.")]
+    TripNotFound { trip_id: String, eva: String },
     #[error(transparent)]
     FailedRequest(#[from] reqwest::Error),
 }
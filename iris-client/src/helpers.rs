@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDateTime, TimeZone};
+use chrono::{DateTime, Duration, DurationRound, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::{Europe::Berlin, Tz};
 
 pub fn parse_iris_date(date_string: &str) -> Option<DateTime<Tz>> {
@@ -10,3 +10,59 @@ pub fn parse_iris_date(date_string: &str) -> Option<DateTime<Tz>> {
 
     Berlin.from_local_datetime(&date).single()
 }
+
+/// The stop id of IRIS is the trip id followed by the index of the stop within the trip.
+pub fn trip_id(stop_id: &str) -> &str {
+    stop_id
+        .rsplit_once('-')
+        .map(|(trip_id, _)| trip_id)
+        .unwrap_or(stop_id)
+}
+
+/// Whether the stop with the id `stop_id` belongs to the trip with the id `trip_id`.
+pub fn is_stop_of_trip(stop_id: &str, trip_id: &str) -> bool {
+    self::trip_id(stop_id) == trip_id
+}
+
+/// Iterates over the IRIS planned timetable slots between `start` and `end` (both inclusive).
+///
+/// IRIS splits its planned data into slots of one hour in Berlin local time, addressed by the date in the format `YYMMDD` and the hour in the format `HH`. \
+/// Every slot is yielded exactly once, even on the days Berlin switches to or from daylight saving time.
+pub fn hour_slots<T: TimeZone>(start: DateTime<T>, end: DateTime<T>) -> HourSlots {
+    let hour = Duration::hours(1);
+
+    HourSlots {
+        current: start.with_timezone(&Utc).duration_trunc(hour).ok(),
+        end: end.with_timezone(&Utc),
+        last: None,
+    }
+}
+
+pub struct HourSlots {
+    current: Option<DateTime<Utc>>,
+    end: DateTime<Utc>,
+    last: Option<(String, String)>,
+}
+
+impl Iterator for HourSlots {
+    type Item = (String, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let current = self.current.filter(|current| *current <= self.end)?;
+            self.current = Some(current + Duration::hours(1));
+
+            let local = current.with_timezone(&Berlin);
+            let slot = (
+                local.format("%y%m%d").to_string(),
+                local.format("%H").to_string(),
+            );
+
+            // the hour before the switch from daylight saving time happens twice in local time
+            if self.last.as_ref() != Some(&slot) {
+                self.last = Some(slot.clone());
+                return Some(slot);
+            }
+        }
+    }
+}
@@ -8,6 +8,8 @@ pub use endpoints::*;
 use reqwest::{Certificate, Client, Proxy};
 
 pub mod helpers;
+pub mod realtime_store;
+pub mod source;
 
 pub struct IrisClient {
     client: reqwest::Client,
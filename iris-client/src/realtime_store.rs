@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use async_lock::RwLock;
+
+use crate::station_board::response::{ArrivalDeparture, Message, TimeTable, TimetableStop};
+use crate::{IrisClient, IrisOrRequestError};
+
+/// IRIS only returns the changes of the last two minutes as recent changes, \
+/// so a station has to be updated at least this often to not miss any changes.
+const RECENT_CHANGES_WINDOW: Duration = Duration::from_secs(110);
+/// How long recent changes are applied on top of a full snapshot before the full realtime information is fetched again, \
+/// so stops IRIS no longer returns are dropped and missed or wrong changes do not stay forever.
+const MAX_BASELINE_AGE: Duration = Duration::from_secs(10 * 60);
+
+/// Keeps the realtime information of stations in memory and keeps them up to date \
+/// by applying the recent changes (`rchg`) IRIS returns on top of a full (`fchg`) snapshot.
+///
+/// The full realtime information is fetched again if a station was not updated within the time frame \
+/// IRIS returns recent changes for, if its snapshot is older than the maximum baseline age \
+/// or if the recent changes could not be fetched.
+pub struct RealtimeStore {
+    stations: RwLock<HashMap<String, StoredRealtime>>,
+    max_baseline_age: Duration,
+}
+
+struct StoredRealtime {
+    timetable: TimeTable,
+    /// When the full snapshot was fetched
+    baseline: Instant,
+    updated: Instant,
+}
+
+impl StoredRealtime {
+    fn is_stale(&self) -> bool {
+        self.updated.elapsed() >= RECENT_CHANGES_WINDOW
+    }
+}
+
+impl Default for RealtimeStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RealtimeStore {
+    pub fn new() -> Self {
+        Self::with_max_baseline_age(MAX_BASELINE_AGE)
+    }
+
+    /// Creates a store that fetches the full realtime information of a station again \
+    /// once its snapshot is older than `max_baseline_age`.
+    pub fn with_max_baseline_age(max_baseline_age: Duration) -> Self {
+        Self {
+            stations: RwLock::new(HashMap::new()),
+            max_baseline_age,
+        }
+    }
+
+    /// Get the current realtime information of a station.
+    ///
+    /// Only fetches the recent changes if the station [is synced](RealtimeStore::is_synced), \
+    /// otherwise or if the recent changes can not be fetched the full realtime information is fetched.
+    pub async fn realtime(
+        &self,
+        iris_client: &IrisClient,
+        eva: &str,
+    ) -> Result<TimeTable, IrisOrRequestError> {
+        self.evict_stale().await;
+
+        if self.is_synced(eva).await {
+            if let Ok(changes) = iris_client.recent_changes(eva).await {
+                if let Some(timetable) = self.apply_changes(eva, changes).await {
+                    return Ok(timetable);
+                }
+            }
+        }
+
+        let timetable = iris_client.realtime_station_board(eva).await?;
+
+        self.insert(eva, timetable.clone()).await;
+
+        Ok(timetable)
+    }
+
+    /// Get the stored realtime information of a station, if there is any.
+    pub async fn get(&self, eva: &str) -> Option<TimeTable> {
+        self.stations
+            .read()
+            .await
+            .get(eva)
+            .map(|stored| stored.timetable.clone())
+    }
+
+    /// Whether recent changes can be applied to the stored realtime information of a station.
+    ///
+    /// That is the case if the station was updated within the recent changes time frame \
+    /// and its snapshot is not older than the maximum baseline age.
+    pub async fn is_synced(&self, eva: &str) -> bool {
+        self.stations
+            .read()
+            .await
+            .get(eva)
+            .map(|stored| !stored.is_stale() && stored.baseline.elapsed() < self.max_baseline_age)
+            .unwrap_or(false)
+    }
+
+    /// Stores full realtime information (`fchg`) of a station as the new baseline.
+    ///
+    /// Stations that have not been updated within the recent changes time frame are removed.
+    pub async fn insert(&self, eva: &str, timetable: TimeTable) {
+        let mut stations = self.stations.write().await;
+
+        stations.retain(|_, stored| !stored.is_stale());
+
+        let now = Instant::now();
+
+        stations.insert(
+            eva.to_string(),
+            StoredRealtime {
+                timetable,
+                baseline: now,
+                updated: now,
+            },
+        );
+    }
+
+    /// Removes all stations that have not been updated within the recent changes time frame, \
+    /// since recent changes can not bring their realtime information up to date anymore.
+    pub async fn evict_stale(&self) {
+        self.stations
+            .write()
+            .await
+            .retain(|_, stored| !stored.is_stale());
+    }
+
+    /// Applies recent changes (`rchg`) to the stored realtime information of a station.
+    ///
+    /// Returns the updated realtime information or `None` if there is no baseline for this station.
+    pub async fn apply_changes(&self, eva: &str, changes: TimeTable) -> Option<TimeTable> {
+        let mut stations = self.stations.write().await;
+
+        let stored = stations.get_mut(eva)?;
+
+        merge_recent_changes(&mut stored.timetable, changes);
+        stored.updated = Instant::now();
+
+        Some(stored.timetable.clone())
+    }
+}
+
+/// Merges recent changes into a realtime timetable.
+///
+/// Stops are merged by their id, attributes that are set in the changes overwrite the existing ones \
+/// and messages marked as deleted are removed.
+pub fn merge_recent_changes(timetable: &mut TimeTable, changes: TimeTable) {
+    merge_messages(&mut timetable.disruptions, changes.disruptions);
+
+    for change in changes.stops {
+        match timetable.stops.iter_mut().find(|stop| stop.id == change.id) {
+            Some(stop) => merge_stop(stop, change),
+            None => {
+                let mut stop = change;
+                remove_deleted_messages(&mut stop);
+                timetable.stops.push(stop);
+            }
+        }
+    }
+}
+
+fn merge_stop(stop: &mut TimetableStop, change: TimetableStop) {
+    merge_option(&mut stop.eva_number, change.eva_number);
+    merge_option(&mut stop.trip_label, change.trip_label);
+    merge_option(&mut stop.reference, change.reference);
+    merge_option(
+        &mut stop.reference_trip_relation,
+        change.reference_trip_relation,
+    );
+
+    if let Some(messages) = change.messages {
+        merge_messages(stop.messages.get_or_insert_with(Vec::new), messages);
+    }
+
+    for connection in change.connection {
+        stop.connection
+            .retain(|existing| existing.id != connection.id);
+        stop.connection.push(connection);
+    }
+
+    merge_event(&mut stop.arrival, change.arrival);
+    merge_event(&mut stop.departure, change.departure);
+}
+
+fn merge_event(event: &mut Option<ArrivalDeparture>, change: Option<ArrivalDeparture>) {
+    let Some(change) = change else {
+        return;
+    };
+
+    let Some(event) = event else {
+        let mut change = change;
+        change.messages.retain(|message| !is_deleted(message));
+        *event = Some(change);
+        return;
+    };
+
+    merge_option(
+        &mut event.changed_distant_endpoint,
+        change.changed_distant_endpoint,
+    );
+    merge_option(&mut event.cancellation_time, change.cancellation_time);
+    merge_option(&mut event.changed_platform, change.changed_platform);
+    merge_option(&mut event.changed_path, change.changed_path);
+    merge_option(&mut event.real_event_status, change.real_event_status);
+    merge_option(&mut event.changed_time, change.changed_time);
+    merge_option(&mut event.distant_change, change.distant_change);
+    merge_option(&mut event.hidden, change.hidden);
+    merge_option(&mut event.line_indicator, change.line_indicator);
+    merge_option(
+        &mut event.planned_distant_endpoint,
+        change.planned_distant_endpoint,
+    );
+    merge_option(&mut event.planned_platform, change.planned_platform);
+    merge_option(&mut event.planned_path, change.planned_path);
+    merge_option(&mut event.planned_event_status, change.planned_event_status);
+    merge_option(&mut event.planned_time, change.planned_time);
+    merge_option(&mut event.transition, change.transition);
+    merge_option(&mut event.wings, change.wings);
+
+    merge_messages(&mut event.messages, change.messages);
+}
+
+fn merge_option<T>(value: &mut Option<T>, change: Option<T>) {
+    if change.is_some() {
+        *value = change;
+    }
+}
+
+fn merge_messages(messages: &mut Vec<Message>, changes: Vec<Message>) {
+    for change in changes {
+        messages.retain(|message| message.id != change.id);
+
+        if !is_deleted(&change) {
+            messages.push(change);
+        }
+    }
+}
+
+fn remove_deleted_messages(stop: &mut TimetableStop) {
+    if let Some(messages) = &mut stop.messages {
+        messages.retain(|message| !is_deleted(message));
+    }
+    for event in stop.arrival.iter_mut().chain(stop.departure.iter_mut()) {
+        event.messages.retain(|message| !is_deleted(message));
+    }
+}
+
+fn is_deleted(message: &Message) -> bool {
+    message.deleted == Some(1)
+}
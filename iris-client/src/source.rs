@@ -0,0 +1,44 @@
+use crate::station_board::response::TimeTable;
+use crate::station_lookup::IrisStation;
+use crate::{IrisClient, IrisOrRequestError};
+
+/// Where station boards and journeys get the data of IRIS from.
+///
+/// The [`IrisClient`] requests IRIS directly, \
+/// other implementations can put a cache or a [`RealtimeStore`](crate::realtime_store::RealtimeStore) in front of it.
+#[async_trait::async_trait]
+pub trait IrisSource: Sync {
+    /// The planned timetable of a station at the specified date (`YYMMDD`) and hour (`HH`).
+    async fn planned(
+        &self,
+        eva: &str,
+        date: &str,
+        hour: &str,
+    ) -> Result<TimeTable, IrisOrRequestError>;
+
+    /// The current realtime information of a station.
+    async fn realtime(&self, eva: &str) -> Result<TimeTable, IrisOrRequestError>;
+
+    /// The stations matching a name, eva number or DS100 code.
+    async fn stations(&self, pattern: &str) -> Result<Vec<IrisStation>, IrisOrRequestError>;
+}
+
+#[async_trait::async_trait]
+impl IrisSource for IrisClient {
+    async fn planned(
+        &self,
+        eva: &str,
+        date: &str,
+        hour: &str,
+    ) -> Result<TimeTable, IrisOrRequestError> {
+        self.planned_station_board(eva, date, hour).await
+    }
+
+    async fn realtime(&self, eva: &str) -> Result<TimeTable, IrisOrRequestError> {
+        self.realtime_station_board(eva).await
+    }
+
+    async fn stations(&self, pattern: &str) -> Result<Vec<IrisStation>, IrisOrRequestError> {
+        self.station_lookup(pattern).await
+    }
+}
@@ -0,0 +1,128 @@
+// Every test binary only uses a part of the shared helpers
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeZone};
+use chrono_tz::{Europe::Berlin, Tz};
+use iris_client::source::IrisSource;
+use iris_client::station_board::response::TimeTable;
+use iris_client::station_board::{from_iris_timetables, IrisStationBoard, TransformMode};
+use iris_client::station_lookup::{response::StationsResponse, IrisStation};
+use iris_client::{IrisError, IrisOrRequestError};
+
+pub const PLAN: &str = include_str!("../fixtures/plan_8000105.xml");
+pub const REALTIME: &str = include_str!("../fixtures/fchg_8000105.xml");
+pub const RECENT_CHANGES: &str = include_str!("../fixtures/rchg_8000105.xml");
+pub const MALFORMED_PLAN: &str = include_str!("../fixtures/plan_8000105_malformed.xml");
+pub const WINGS_PLAN: &str = include_str!("../fixtures/plan_8011160_wings.xml");
+pub const STATION: &str = include_str!("../fixtures/station_FF.xml");
+
+pub fn timetable(xml: &str) -> TimeTable {
+    serde_xml_rs::from_str(xml).expect("Failed to parse timetable fixture")
+}
+
+/// The planned timetable of Frankfurt(Main)Hbf from 13:00 to 14:00 on 2023-10-18
+pub fn plan() -> TimeTable {
+    timetable(PLAN)
+}
+
+/// The full realtime information of Frankfurt(Main)Hbf
+pub fn realtime() -> TimeTable {
+    timetable(REALTIME)
+}
+
+pub fn recent_changes() -> TimeTable {
+    timetable(RECENT_CHANGES)
+}
+
+/// The hour from `hour`:00 to `hour + 1`:00 on 2023-10-18, the day of the fixtures
+pub fn hour(hour: u32) -> (DateTime<Tz>, DateTime<Tz>) {
+    (
+        Berlin.with_ymd_and_hms(2023, 10, 18, hour, 0, 0).unwrap(),
+        Berlin
+            .with_ymd_and_hms(2023, 10, 18, hour + 1, 0, 0)
+            .unwrap(),
+    )
+}
+
+/// The station board of Frankfurt(Main)Hbf built from the plan and realtime fixtures
+pub fn station_board() -> IrisStationBoard {
+    let (start, end) = hour(13);
+
+    from_iris_timetables(
+        "8000105",
+        vec![plan()],
+        realtime(),
+        start,
+        end,
+        TransformMode::Strict,
+    )
+    .expect("Failed to transform station board")
+}
+
+pub fn stations(xml: &str) -> Vec<IrisStation> {
+    let response: StationsResponse =
+        serde_xml_rs::from_str(xml).expect("Failed to parse station fixture");
+
+    response
+        .stations
+        .into_iter()
+        .map(IrisStation::from)
+        .collect()
+}
+
+/// An [`IrisSource`] that serves fixtures, everything without a fixture fails like IRIS does.
+#[derive(Default)]
+pub struct FixtureSource {
+    /// The planned timetables by eva number, date and hour
+    pub plans: HashMap<(String, String, String), TimeTable>,
+    /// The realtime information by eva number
+    pub realtime: HashMap<String, TimeTable>,
+    /// The stations by lookup pattern
+    pub stations: HashMap<String, Vec<IrisStation>>,
+}
+
+impl FixtureSource {
+    pub fn with_plan(mut self, eva: &str, date: &str, hour: &str, plan: TimeTable) -> Self {
+        self.plans
+            .insert((eva.to_owned(), date.to_owned(), hour.to_owned()), plan);
+        self
+    }
+
+    pub fn with_realtime(mut self, eva: &str, realtime: TimeTable) -> Self {
+        self.realtime.insert(eva.to_owned(), realtime);
+        self
+    }
+
+    pub fn with_stations(mut self, pattern: &str, stations: Vec<IrisStation>) -> Self {
+        self.stations.insert(pattern.to_owned(), stations);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl IrisSource for FixtureSource {
+    async fn planned(
+        &self,
+        eva: &str,
+        date: &str,
+        hour: &str,
+    ) -> Result<TimeTable, IrisOrRequestError> {
+        self.plans
+            .get(&(eva.to_owned(), date.to_owned(), hour.to_owned()))
+            .cloned()
+            .ok_or(IrisOrRequestError::IrisError(IrisError))
+    }
+
+    async fn realtime(&self, eva: &str) -> Result<TimeTable, IrisOrRequestError> {
+        self.realtime
+            .get(eva)
+            .cloned()
+            .ok_or(IrisOrRequestError::IrisError(IrisError))
+    }
+
+    async fn stations(&self, pattern: &str) -> Result<Vec<IrisStation>, IrisOrRequestError> {
+        Ok(self.stations.get(pattern).cloned().unwrap_or_default())
+    }
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use chrono::TimeZone;
+use chrono_tz::Europe::Berlin;
+use iris_client::station_board::StationBoardStop;
+
+fn station_board() -> Vec<StationBoardStop> {
+    common::station_board().stops
+}
+
+fn stop(stops: &[StationBoardStop], train_number: &str) -> StationBoardStop {
+    stops
+        .iter()
+        .find(|stop| stop.train_number == train_number)
+        .cloned()
+        .expect("Train is on the station board")
+}
+
+#[test]
+fn delays_are_computed() {
+    let stops = station_board();
+
+    let ice = stop(&stops, "1011");
+    assert_eq!(ice.arrival_delay_minutes, Some(5));
+    assert_eq!(ice.departure_delay_minutes, Some(4));
+
+    let rb = stop(&stops, "15519");
+    assert_eq!(rb.arrival_delay_minutes, None, "RB 15519 has no arrival");
+    assert_eq!(
+        rb.departure_delay_minutes, None,
+        "There is no realtime time for RB 15519"
+    );
+
+    let added = stop(&stops, "9590");
+    assert_eq!(added.arrival_delay_minutes, Some(0));
+    assert_eq!(added.departure_delay_minutes, Some(0));
+}
+
+#[test]
+fn platform_changes_use_the_changed_platform() {
+    let stops = station_board();
+
+    let ice = stop(&stops, "1011");
+    assert_eq!(ice.planned_platform.as_deref(), Some("7"));
+    assert_eq!(ice.real_platform.as_deref(), Some("9"));
+    assert!(ice.platform_changed);
+
+    let rb = stop(&stops, "15519");
+    assert_eq!(rb.planned_platform.as_deref(), Some("19"));
+    assert_eq!(rb.real_platform, None);
+    assert!(!rb.platform_changed);
+}
+
+#[test]
+fn route_changes_are_detected() {
+    let stops = station_board();
+
+    assert!(
+        stop(&stops, "1011").route_changed,
+        "Augsburg Hbf was removed from the route of ICE 1011"
+    );
+    assert!(!stop(&stops, "15519").route_changed);
+}
+
+#[test]
+fn cancellation_and_distant_changes_are_transformed() {
+    let stops = station_board();
+
+    let rb = stop(&stops, "15519");
+    assert!(rb.cancelled);
+    assert_eq!(
+        rb.cancellation_time,
+        Some(
+            Berlin
+                .with_ymd_and_hms(2023, 10, 18, 11, 40, 0)
+                .unwrap()
+                .fixed_offset()
+        )
+    );
+    assert_eq!(rb.distant_change, None);
+
+    let ice = stop(&stops, "1011");
+    assert_eq!(ice.cancellation_time, None);
+    assert_eq!(ice.distant_change, Some(1));
+}
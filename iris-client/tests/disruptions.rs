@@ -0,0 +1,115 @@
+use chrono::TimeZone;
+use chrono_tz::Europe::Berlin;
+use iris_client::disruptions::from_realtime_timetable;
+use iris_client::station_board::response::TimeTable;
+use iris_client::station_board::{DisruptionDistributorType, DisruptionTrip};
+use iris_client::IrisTransformError;
+
+const DISRUPTIONS: &str = include_str!("fixtures/fchg_8000105_disruptions.xml");
+
+fn realtime() -> TimeTable {
+    serde_xml_rs::from_str(DISRUPTIONS).expect("Failed to parse disruptions fixture")
+}
+
+#[tokio::test]
+async fn station_disruptions() {
+    let iris_client = iris_client::IrisClient::default();
+
+    let response = iris_client.station_disruptions("8000105").await;
+
+    assert!(response.is_ok(), "Response is not ok: {response:?}");
+
+    println!("{:#?}", response.unwrap())
+}
+
+#[test]
+fn disruptions_are_split_by_validity() {
+    let now = Berlin.with_ymd_and_hms(2023, 10, 18, 13, 0, 0).unwrap();
+
+    let disruptions = from_realtime_timetable("8000105", realtime(), now);
+
+    assert_eq!(disruptions.station_name, "Frankfurt(Main)Hbf");
+    assert_eq!(
+        disruptions
+            .active
+            .iter()
+            .map(|disruption| disruption.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["r2076190005", "r2076190001"],
+        "Disruptions without a start come first"
+    );
+    assert!(disruptions
+        .active
+        .iter()
+        .all(|disruption| disruption.active));
+    assert_eq!(
+        disruptions
+            .upcoming
+            .iter()
+            .map(|disruption| disruption.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["r2076190002"]
+    );
+    assert!(!disruptions.upcoming[0].active);
+    assert_eq!(
+        disruptions.warnings,
+        vec![IrisTransformError::InvalidDate {
+            date: String::from("2310")
+        }]
+    );
+}
+
+#[test]
+fn disruption_details_are_kept() {
+    let now = Berlin.with_ymd_and_hms(2023, 10, 18, 13, 0, 0).unwrap();
+
+    let disruptions = from_realtime_timetable("8000105", realtime(), now);
+    let disruption = disruptions
+        .active
+        .iter()
+        .find(|disruption| disruption.id == "r2076190001")
+        .expect("Disruption r2076190001 is active");
+
+    assert_eq!(
+        disruption.external_text.as_deref(),
+        Some("Gleis 7 ist gesperrt, Züge fahren von Gleis 9.")
+    );
+    assert_eq!(
+        disruption.external_link.as_deref(),
+        Some("https://www.bahn.de/bauarbeiten")
+    );
+    assert_eq!(
+        disruption.internal_text.as_deref(),
+        Some("Weichenstörung, Entstörer informiert")
+    );
+    assert_eq!(disruption.external_category.as_deref(), Some("Bauarbeiten"));
+    assert_eq!(
+        disruption.valid_from,
+        Some(
+            Berlin
+                .with_ymd_and_hms(2023, 10, 18, 12, 0, 0)
+                .unwrap()
+                .fixed_offset()
+        )
+    );
+    assert_eq!(
+        disruption.affected_trips,
+        vec![
+            DisruptionTrip {
+                train_type: String::from("ICE"),
+                train_number: String::from("1011"),
+                owner: String::from("80"),
+            },
+            DisruptionTrip {
+                train_type: String::from("RB"),
+                train_number: String::from("15519"),
+                owner: String::from("800337"),
+            }
+        ]
+    );
+    assert_eq!(disruption.distributor_messages.len(), 1);
+    assert_eq!(
+        disruption.distributor_messages[0].distributor_type,
+        Some(DisruptionDistributorType::Region)
+    );
+}
@@ -0,0 +1,80 @@
+use chrono::{TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use iris_client::helpers::hour_slots;
+
+fn slots(slots: &[(&str, &str)]) -> Vec<(String, String)> {
+    slots
+        .iter()
+        .map(|(date, hour)| (date.to_string(), hour.to_string()))
+        .collect()
+}
+
+#[test]
+fn hours_within_a_day() {
+    let start = Berlin.with_ymd_and_hms(2023, 10, 18, 12, 40, 0).unwrap();
+    let end = Berlin.with_ymd_and_hms(2023, 10, 18, 15, 40, 0).unwrap();
+
+    assert_eq!(
+        hour_slots(start, end).collect::<Vec<_>>(),
+        slots(&[
+            ("231018", "12"),
+            ("231018", "13"),
+            ("231018", "14"),
+            ("231018", "15")
+        ])
+    );
+}
+
+#[test]
+fn window_crossing_midnight() {
+    let start = Berlin.with_ymd_and_hms(2023, 10, 18, 23, 50, 0).unwrap();
+    let end = Berlin.with_ymd_and_hms(2023, 10, 19, 0, 10, 0).unwrap();
+
+    assert_eq!(
+        hour_slots(start, end).collect::<Vec<_>>(),
+        slots(&[("231018", "23"), ("231019", "00")])
+    );
+}
+
+#[test]
+fn window_within_one_hour() {
+    let start = Berlin.with_ymd_and_hms(2023, 10, 18, 13, 5, 0).unwrap();
+    let end = Berlin.with_ymd_and_hms(2023, 10, 18, 13, 55, 0).unwrap();
+
+    assert_eq!(
+        hour_slots(start, end).collect::<Vec<_>>(),
+        slots(&[("231018", "13")])
+    );
+}
+
+#[test]
+fn end_before_start_is_empty() {
+    let start = Berlin.with_ymd_and_hms(2023, 10, 18, 14, 0, 0).unwrap();
+    let end = Berlin.with_ymd_and_hms(2023, 10, 18, 12, 0, 0).unwrap();
+
+    assert_eq!(hour_slots(start, end).count(), 0);
+}
+
+#[test]
+fn switch_from_daylight_saving_time() {
+    // 2023-10-29 03:00 CEST becomes 02:00 CET, so the 02 hour happens twice
+    let start = Utc.with_ymd_and_hms(2023, 10, 28, 23, 30, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 10, 29, 2, 30, 0).unwrap();
+
+    assert_eq!(
+        hour_slots(start, end).collect::<Vec<_>>(),
+        slots(&[("231029", "01"), ("231029", "02"), ("231029", "03")])
+    );
+}
+
+#[test]
+fn switch_to_daylight_saving_time() {
+    // 2023-03-26 02:00 CET becomes 03:00 CEST, so there is no 02 hour
+    let start = Berlin.with_ymd_and_hms(2023, 3, 26, 1, 30, 0).unwrap();
+    let end = Berlin.with_ymd_and_hms(2023, 3, 26, 4, 30, 0).unwrap();
+
+    assert_eq!(
+        hour_slots(start, end).collect::<Vec<_>>(),
+        slots(&[("230326", "01"), ("230326", "03"), ("230326", "04")])
+    );
+}
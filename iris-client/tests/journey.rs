@@ -0,0 +1,256 @@
+mod common;
+
+use chrono::TimeZone;
+use chrono_tz::Europe::Berlin;
+use common::FixtureSource;
+use iris_client::journey::{
+    find_planned_trip_stop, find_realtime_trip_stop, journey_from, parse_trip_id, IrisJourneyStop,
+};
+use iris_client::station_lookup::IrisStation;
+use iris_client::IrisOrRequestError;
+
+#[tokio::test]
+async fn journey() {
+    let iris_client = iris_client::IrisClient::default();
+
+    let station_board = iris_client
+        .station_board("8000105", None, Some(60), Some(0))
+        .await
+        .expect("Failed to get station board");
+
+    let stop = station_board
+        .stops
+        .first()
+        .expect("Station board has no stops");
+
+    let response = iris_client.journey(&stop.id, "8000105").await;
+
+    assert!(response.is_ok(), "Response is not ok: {response:?}");
+
+    let response = response.unwrap();
+
+    println!("{response:#?}")
+}
+
+#[test]
+fn trip_ids_are_parsed() {
+    let start = Berlin.with_ymd_and_hms(2023, 10, 18, 12, 4, 0).unwrap();
+
+    assert_eq!(
+        parse_trip_id("-5405254403468434390-2310181204"),
+        Some((String::from("-5405254403468434390-2310181204"), start))
+    );
+    assert_eq!(
+        parse_trip_id("-5405254403468434390-2310181204-8"),
+        Some((String::from("-5405254403468434390-2310181204"), start)),
+        "Stop ids are reduced to the trip id"
+    );
+    assert_eq!(
+        parse_trip_id("5405254403468434390-2310181204"),
+        Some((String::from("5405254403468434390-2310181204"), start))
+    );
+    assert_eq!(parse_trip_id("5405254403468434390"), None);
+    assert_eq!(parse_trip_id("5405254403468434390-2313991204"), None);
+    assert_eq!(parse_trip_id("1-2310181204-8-1"), None);
+}
+
+#[test]
+fn planned_trip_stops_are_found() {
+    let (plan, realtime) = (common::plan(), common::realtime());
+
+    let stop = find_planned_trip_stop(
+        "8000105",
+        "-5405254403468434390-2310181204",
+        &plan,
+        &realtime,
+        &mut Vec::new(),
+    )
+    .expect("Failed to transform ICE 1011")
+    .expect("ICE 1011 is in the plan");
+
+    let stop = IrisJourneyStop::from(stop);
+
+    assert_eq!(stop.stop_id, "-5405254403468434390-2310181204-8");
+    assert_eq!(stop.index, 8);
+    assert_eq!(stop.station_name, "Frankfurt(Main)Hbf");
+    assert_eq!(stop.real_platform.as_deref(), Some("9"));
+
+    let arrival = stop.arrival.expect("ICE 1011 has an arrival");
+    assert_eq!(
+        arrival.planned_time,
+        Berlin.with_ymd_and_hms(2023, 10, 18, 13, 2, 0).unwrap()
+    );
+    assert_eq!(arrival.delay_minutes, Some(5));
+    assert_eq!(stop.messages.len(), 1);
+
+    assert_eq!(
+        find_planned_trip_stop("8000105", "1-2310181204", &plan, &realtime, &mut Vec::new()),
+        Ok(None)
+    );
+}
+
+#[test]
+fn realtime_trip_stops_are_found() {
+    let realtime = common::realtime();
+
+    let stop = find_realtime_trip_stop(
+        "8000105",
+        "-2312581224139581329-2310181230",
+        &realtime,
+        &mut Vec::new(),
+    )
+    .expect("Failed to transform ICE 9590")
+    .expect("ICE 9590 is in the realtime data");
+
+    let stop = IrisJourneyStop::from(stop);
+
+    assert_eq!(stop.index, 101);
+    assert!(stop.added);
+    assert_eq!(stop.real_platform.as_deref(), Some("8"));
+}
+
+fn station(name: &str, eva: &str) -> IrisStation {
+    IrisStation {
+        name: name.to_owned(),
+        eva: eva.to_owned(),
+        ds100: String::new(),
+        meta: Vec::new(),
+        platforms: Vec::new(),
+        db: true,
+    }
+}
+
+fn empty_realtime(station_name: &str) -> iris_client::station_board::response::TimeTable {
+    common::timetable(&format!("<timetable station='{station_name}'/>"))
+}
+
+/// ICE 1011 from Hamburg-Altona to München Hbf with fixtures for Hanau Hbf, Frankfurt(Main)Hbf, Mannheim Hbf and Stuttgart Hbf
+fn journey_source() -> FixtureSource {
+    FixtureSource::default()
+        .with_plan(
+            "8000150",
+            "231018",
+            "12",
+            common::timetable(include_str!("fixtures/plan_8000150_journey.xml")),
+        )
+        .with_plan("8000105", "231018", "13", common::plan())
+        .with_plan(
+            "8000244",
+            "231018",
+            "13",
+            common::timetable(include_str!("fixtures/plan_8000244_journey.xml")),
+        )
+        .with_plan(
+            "8000096",
+            "231018",
+            "14",
+            common::timetable(include_str!("fixtures/plan_8000096_journey.xml")),
+        )
+        .with_realtime("8000105", common::realtime())
+        .with_realtime("8000150", empty_realtime("Hanau Hbf"))
+        .with_realtime("8000244", empty_realtime("Mannheim Hbf"))
+        .with_realtime("8000096", empty_realtime("Stuttgart Hbf"))
+        .with_realtime("8000170", empty_realtime("Ulm Hbf"))
+        .with_stations("Hanau Hbf", vec![station("Hanau Hbf", "8000150")])
+        .with_stations("Mannheim Hbf", vec![station("Mannheim Hbf", "8000244")])
+        .with_stations("Stuttgart Hbf", vec![station("Stuttgart Hbf", "8000096")])
+        .with_stations("Ulm Hbf", vec![station("Ulm Hbf", "8000170")])
+        .with_stations(
+            "Fulda",
+            vec![
+                station("Fulda Süd", "8079031"),
+                station("Fulda-Lehnerz", "8001981"),
+            ],
+        )
+}
+
+#[tokio::test]
+async fn journeys_are_reconstructed() {
+    let journey = journey_from(
+        &journey_source(),
+        "-5405254403468434390-2310181204-8",
+        "8000105",
+    )
+    .await
+    .expect("Failed to reconstruct ICE 1011");
+
+    assert_eq!(journey.trip_id, "-5405254403468434390-2310181204");
+    assert_eq!(journey.train_type, "ICE");
+    assert_eq!(journey.train_number, "1011");
+
+    assert_eq!(
+        journey
+            .stops
+            .iter()
+            .map(|stop| (stop.index, stop.station_name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (7, "Hanau Hbf"),
+            (8, "Frankfurt(Main)Hbf"),
+            (9, "Mannheim Hbf"),
+            (10, "Stuttgart Hbf")
+        ]
+    );
+    assert_eq!(journey.stops[1].real_platform.as_deref(), Some("9"));
+
+    assert_eq!(
+        journey.missing_stations,
+        vec![
+            "Hamburg-Altona",
+            "Hamburg Hbf",
+            "Hannover Hbf",
+            "Kassel-Wilhelmshöhe",
+            "Fulda",
+            "Ulm Hbf",
+            "Augsburg Hbf",
+            "München Hbf"
+        ],
+        "Fulda is missing although the lookup found other stations with a similar name"
+    );
+    assert!(journey.warnings.is_empty());
+}
+
+#[tokio::test]
+async fn unavailable_stations_are_missing() {
+    let mut source = journey_source();
+    source.realtime.remove("8000244");
+
+    let journey = journey_from(&source, "-5405254403468434390-2310181204", "8000105")
+        .await
+        .expect("The journey is reconstructed although Mannheim Hbf is unavailable");
+
+    assert_eq!(
+        journey
+            .stops
+            .iter()
+            .map(|stop| stop.station_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Hanau Hbf", "Frankfurt(Main)Hbf", "Stuttgart Hbf"]
+    );
+    assert!(journey
+        .missing_stations
+        .contains(&String::from("Mannheim Hbf")));
+    assert_eq!(
+        journey
+            .unavailable_stations
+            .iter()
+            .map(|station| station.station.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Mannheim Hbf"]
+    );
+}
+
+#[tokio::test]
+async fn journeys_need_a_stop_at_the_station() {
+    let result = journey_from(
+        &journey_source(),
+        "-5405254403468434390-2310181204",
+        "8000170",
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(IrisOrRequestError::TripNotFound { eva, .. }) if eva == "8000170"
+    ));
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use iris_client::station_board::message::lookup::{
+    iris_message_classification, iris_message_lookup, iris_message_text,
+};
+use iris_client::station_board::message::{MessageCodeCategory, MessageLanguage, MessageSeverity};
+use iris_client::station_board::{from_iris_timetable, StationBoardStop, TransformMode};
+
+#[test]
+fn message_texts_are_localized() {
+    assert_eq!(
+        iris_message_text(43, MessageLanguage::German),
+        Some("Verspätung eines vorausfahrenden Zuges")
+    );
+    assert_eq!(
+        iris_message_text(43, MessageLanguage::English),
+        Some("Delay of a preceding train")
+    );
+    assert_eq!(
+        iris_message_lookup(&96).as_deref(),
+        Some("Der Zug ist stark überbesetzt")
+    );
+    assert_eq!(iris_message_text(27, MessageLanguage::English), None);
+}
+
+#[test]
+fn message_codes_are_classified() {
+    assert_eq!(
+        iris_message_classification(35),
+        Some((MessageCodeCategory::DelayCause, MessageSeverity::High))
+    );
+    assert_eq!(
+        iris_message_classification(43),
+        Some((MessageCodeCategory::DelayCause, MessageSeverity::Medium))
+    );
+    assert_eq!(
+        iris_message_classification(82),
+        Some((MessageCodeCategory::Quality, MessageSeverity::Medium))
+    );
+    assert_eq!(
+        iris_message_classification(70),
+        Some((MessageCodeCategory::Quality, MessageSeverity::Low))
+    );
+    assert_eq!(
+        iris_message_classification(1000),
+        Some((MessageCodeCategory::Information, MessageSeverity::Low))
+    );
+    assert_eq!(iris_message_classification(27), None);
+}
+
+#[test]
+fn language_tags_are_matched() {
+    assert_eq!(
+        MessageLanguage::from_language_tag("en-GB"),
+        Some(MessageLanguage::English)
+    );
+    assert_eq!(
+        MessageLanguage::from_language_tag(" de_DE"),
+        Some(MessageLanguage::German)
+    );
+    assert_eq!(MessageLanguage::from_language_tag("fr"), None);
+}
+
+#[test]
+fn stop_messages_are_localized() {
+    let (plan, realtime) = (common::plan(), common::realtime());
+
+    let stop = plan
+        .stops
+        .into_iter()
+        .find(|stop| stop.id == "-5405254403468434390-2310181204-8")
+        .expect("ICE 1011 is in the plan");
+    let realtime_stop = realtime
+        .stops
+        .into_iter()
+        .find(|realtime_stop| realtime_stop.id == stop.id);
+
+    let mut stop: StationBoardStop = from_iris_timetable(
+        "8000105",
+        &plan.station_name,
+        stop,
+        realtime_stop,
+        TransformMode::Strict,
+        &mut Vec::new(),
+    )
+    .expect("Failed to transform ICE 1011");
+
+    let message = &stop.messages[0];
+    assert_eq!(
+        message.matched_text.as_deref(),
+        Some("Verspätung eines vorausfahrenden Zuges")
+    );
+    assert_eq!(message.code_category, Some(MessageCodeCategory::DelayCause));
+    assert_eq!(message.severity, Some(MessageSeverity::Medium));
+
+    stop.messages[0].localize(MessageLanguage::English);
+    assert_eq!(
+        stop.messages[0].matched_text.as_deref(),
+        Some("Delay of a preceding train")
+    );
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "quick-xml")]
+
+use iris_client::station_board::parser::parse_timetable;
+use iris_client::station_board::response::TimeTable;
+
+const FIXTURES: [(&str, &str); 6] = [
+    ("plan", include_str!("fixtures/plan_8000105.xml")),
+    ("fchg", include_str!("fixtures/fchg_8000105.xml")),
+    ("rchg", include_str!("fixtures/rchg_8000105.xml")),
+    (
+        "malformed plan",
+        include_str!("fixtures/plan_8000105_malformed.xml"),
+    ),
+    ("wings", include_str!("fixtures/plan_8011160_wings.xml")),
+    (
+        "disruptions",
+        include_str!("fixtures/fchg_8000105_disruptions.xml"),
+    ),
+];
+
+#[test]
+fn parsers_are_equivalent() {
+    for (name, fixture) in FIXTURES {
+        let expected: TimeTable = serde_xml_rs::from_str(fixture)
+            .unwrap_or_else(|err| panic!("serde_xml_rs failed to parse {name}: {err}"));
+        let parsed = parse_timetable(fixture)
+            .unwrap_or_else(|err| panic!("quick-xml failed to parse {name}: {err}"));
+
+        assert_eq!(parsed, expected, "Parsers disagree on {name}");
+    }
+}
+
+#[test]
+fn escaped_attributes_are_unescaped() {
+    let xml = r#"<timetable station="Frankfurt(Main)Hbf"><s id="1-2310181204-1"><tl c="RE" n="1" o="800337"/><dp pt="2310181204" ppth="Hanau Hbf|Aschaffenburg &amp; Umgebung"/></s></timetable>"#;
+
+    let expected: TimeTable = serde_xml_rs::from_str(xml).expect("serde_xml_rs failed");
+    let parsed = parse_timetable(xml).expect("quick-xml failed");
+
+    assert_eq!(parsed, expected);
+    assert_eq!(
+        parsed.stops[0]
+            .departure
+            .as_ref()
+            .and_then(|departure| departure.planned_path.as_deref()),
+        Some("Hanau Hbf|Aschaffenburg & Umgebung")
+    );
+}
+
+#[test]
+fn missing_required_attributes_fail() {
+    let xml = r#"<timetable station="Frankfurt(Main)Hbf"><s id="1-2310181204-1"><tl c="RE" o="800337"/></s></timetable>"#;
+
+    let err = parse_timetable(xml).expect_err("The trip label has no train number");
+
+    assert!(err.message.contains("`n`"), "Unexpected error: {err}");
+}
+
+#[test]
+fn unknown_elements_are_skipped() {
+    let xml = r#"<timetable station="Frankfurt(Main)Hbf"><unknown a="b"><nested/></unknown><s id="1-2310181204-1"><extra>text</extra></s></timetable>"#;
+
+    let parsed = parse_timetable(xml).expect("quick-xml failed");
+
+    assert_eq!(parsed.stops.len(), 1);
+    assert_eq!(parsed.stops[0].id, "1-2310181204-1");
+}
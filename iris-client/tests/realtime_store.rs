@@ -0,0 +1,96 @@
+mod common;
+
+use std::time::Duration;
+
+use iris_client::realtime_store::{merge_recent_changes, RealtimeStore};
+
+#[test]
+fn recent_changes_are_merged() {
+    let (mut realtime, recent_changes) = (common::realtime(), common::recent_changes());
+    let stop_count = realtime.stops.len();
+
+    merge_recent_changes(&mut realtime, recent_changes);
+
+    assert_eq!(realtime.stops.len(), stop_count + 1, "RE 90555 was added");
+    assert_eq!(realtime.disruptions.len(), 1);
+
+    let ice = realtime
+        .stops
+        .iter()
+        .find(|stop| stop.id == "-5405254403468434390-2310181204-8")
+        .expect("ICE 1011 is still in the realtime data");
+
+    let arrival = ice.arrival.as_ref().expect("ICE 1011 has an arrival");
+    assert_eq!(arrival.changed_time.as_deref(), Some("2310181311"));
+    assert_eq!(arrival.changed_platform.as_deref(), Some("8"));
+    assert_eq!(
+        arrival
+            .messages
+            .iter()
+            .map(|message| message.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["r2076184790"],
+        "The deleted message was removed"
+    );
+
+    let departure = ice.departure.as_ref().expect("ICE 1011 has a departure");
+    assert_eq!(
+        departure.changed_time.as_deref(),
+        Some("2310181314"),
+        "Unchanged attributes are kept"
+    );
+    assert_eq!(ice.connection.len(), 2, "Connections are kept");
+}
+
+#[tokio::test]
+async fn store_applies_changes_on_baseline() {
+    let (realtime, recent_changes) = (common::realtime(), common::recent_changes());
+
+    let store = RealtimeStore::new();
+
+    assert!(
+        store
+            .apply_changes("8000105", recent_changes.clone())
+            .await
+            .is_none(),
+        "Changes can not be applied without a baseline"
+    );
+
+    store.insert("8000105", realtime).await;
+
+    let updated = store
+        .apply_changes("8000105", recent_changes)
+        .await
+        .expect("Changes are applied on the baseline");
+
+    assert_eq!(store.get("8000105").await, Some(updated));
+}
+
+#[tokio::test]
+async fn baselines_expire() {
+    let realtime = common::realtime();
+
+    let store = RealtimeStore::new();
+
+    assert!(
+        !store.is_synced("8000105").await,
+        "Unknown stations are not synced"
+    );
+
+    store.insert("8000105", realtime.clone()).await;
+
+    assert!(store.is_synced("8000105").await);
+
+    let store = RealtimeStore::with_max_baseline_age(Duration::ZERO);
+
+    store.insert("8000105", realtime).await;
+
+    assert!(
+        !store.is_synced("8000105").await,
+        "Expired baselines are fetched again"
+    );
+    assert!(
+        store.get("8000105").await.is_some(),
+        "Expired baselines are kept until they are replaced"
+    );
+}
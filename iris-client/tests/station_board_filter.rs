@@ -0,0 +1,112 @@
+mod common;
+
+use iris_client::station_board::{StationBoardEventFilter, StationBoardFilter};
+
+use common::station_board;
+
+fn filtered(filter: StationBoardFilter) -> Vec<String> {
+    let mut station_board = station_board();
+    station_board.filter(&filter);
+
+    let mut train_numbers = station_board
+        .stops
+        .into_iter()
+        .map(|stop| stop.train_number)
+        .collect::<Vec<_>>();
+    train_numbers.sort();
+    train_numbers
+}
+
+#[test]
+fn empty_filter_keeps_all_stops() {
+    assert_eq!(
+        filtered(StationBoardFilter::default()),
+        vec!["1011", "15519", "9590"]
+    );
+}
+
+#[test]
+fn stops_are_filtered_by_train_type_and_line() {
+    assert_eq!(
+        filtered(StationBoardFilter {
+            train_types: vec![String::from("ice")],
+            ..Default::default()
+        }),
+        vec!["1011", "9590"]
+    );
+    assert_eq!(
+        filtered(StationBoardFilter {
+            lines: vec![String::from("RB 58")],
+            ..Default::default()
+        }),
+        vec!["15519"]
+    );
+    assert_eq!(
+        filtered(StationBoardFilter {
+            lines: vec![String::from("58")],
+            ..Default::default()
+        }),
+        vec!["15519"]
+    );
+}
+
+#[test]
+fn stops_are_filtered_by_platform_and_via() {
+    assert_eq!(
+        filtered(StationBoardFilter {
+            platforms: vec![String::from("7"), String::from("8")],
+            ..Default::default()
+        }),
+        vec!["9590"],
+        "The changed platform of ICE 1011 is used"
+    );
+    assert_eq!(
+        filtered(StationBoardFilter {
+            via: Some(String::from("hanau")),
+            ..Default::default()
+        }),
+        vec!["15519", "9590"],
+        "ICE 1011 already passed Hanau Hbf before it departs"
+    );
+    assert_eq!(
+        filtered(StationBoardFilter {
+            via: Some(String::from("hanau")),
+            events: Some(StationBoardEventFilter::Arrivals),
+            ..Default::default()
+        }),
+        vec!["1011"],
+        "Arrivals are matched against the route before the station"
+    );
+    assert_eq!(
+        filtered(StationBoardFilter {
+            via: Some(String::from("Mannheim")),
+            ..Default::default()
+        }),
+        vec!["1011"]
+    );
+    assert!(filtered(StationBoardFilter {
+        via: Some(String::from("Mannheim")),
+        events: Some(StationBoardEventFilter::Arrivals),
+        ..Default::default()
+    })
+    .is_empty());
+}
+
+#[test]
+fn stops_are_filtered_by_event_and_state() {
+    assert_eq!(
+        filtered(StationBoardFilter {
+            events: Some(StationBoardEventFilter::Arrivals),
+            ..Default::default()
+        }),
+        vec!["1011", "9590"]
+    );
+    assert_eq!(
+        filtered(StationBoardFilter {
+            events: Some(StationBoardEventFilter::Departures),
+            hide_cancelled: true,
+            ..Default::default()
+        }),
+        vec!["1011", "9590"]
+    );
+}
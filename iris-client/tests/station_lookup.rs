@@ -0,0 +1,52 @@
+mod common;
+
+use iris_client::station_lookup::{meta_stations, response::StationsResponse};
+const UNKNOWN_STATION: &str = include_str!("fixtures/station_unknown.xml");
+
+#[tokio::test]
+async fn station_lookup() {
+    let iris_client = iris_client::IrisClient::default();
+
+    let response = iris_client.station_lookup("FF").await;
+
+    assert!(response.is_ok(), "Response is not ok: {response:?}");
+
+    let response = response.unwrap();
+
+    println!("{response:#?}")
+}
+
+#[test]
+fn stations_are_transformed() {
+    let stations = common::stations(common::STATION);
+
+    assert_eq!(stations.len(), 1);
+
+    let station = &stations[0];
+    assert_eq!(station.name, "Frankfurt(Main)Hbf");
+    assert_eq!(station.eva, "8000105");
+    assert_eq!(station.ds100, "FF");
+    assert_eq!(station.meta, vec!["8089029", "8098105"]);
+    assert_eq!(station.platforms.len(), 28);
+    assert_eq!(station.platforms.first().map(String::as_str), Some("1"));
+    assert!(station.db);
+}
+
+#[test]
+fn unknown_stations_are_empty() {
+    let response: StationsResponse =
+        serde_xml_rs::from_str(UNKNOWN_STATION).expect("Failed to parse station fixture");
+
+    assert!(response.stations.is_empty());
+}
+
+#[test]
+fn meta_stations_are_resolved() {
+    let stations = common::stations(common::STATION);
+
+    assert_eq!(
+        meta_stations("8000105", &stations),
+        vec!["8089029", "8098105"]
+    );
+    assert!(meta_stations("8000106", &stations).is_empty());
+}
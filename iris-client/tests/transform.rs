@@ -0,0 +1,521 @@
+mod common;
+
+use chrono::TimeZone;
+use chrono_tz::Europe::Berlin;
+use common::{FixtureSource, MALFORMED_PLAN, REALTIME, WINGS_PLAN};
+use iris_client::station_board::wing::{Wing, WingPosition};
+use iris_client::station_board::{
+    additional_realtime_stops, connection::ConnectionStatus, from_iris_timetable,
+    from_iris_timetables, meta_station_board_from, reference::ReferenceTripRelation,
+    response::TimeTable, StationBoardStop, TransformMode,
+};
+use iris_client::IrisTransformError;
+
+fn fixtures() -> (TimeTable, Vec<StationBoardStop>) {
+    let (plan, realtime) = (common::plan(), common::realtime());
+
+    let planned_stops = plan
+        .stops
+        .into_iter()
+        .map(|stop| {
+            let realtime_stop = realtime
+                .stops
+                .iter()
+                .find(|realtime_stop| realtime_stop.id == stop.id)
+                .cloned();
+            from_iris_timetable(
+                "8000105",
+                &plan.station_name,
+                stop,
+                realtime_stop,
+                TransformMode::Strict,
+                &mut Vec::new(),
+            )
+            .expect("Failed to transform stop")
+        })
+        .collect::<Vec<_>>();
+
+    (realtime, planned_stops)
+}
+
+#[test]
+fn additional_realtime_stops_are_added() {
+    let (realtime, planned_stops) = fixtures();
+
+    let (start, end) = common::hour(13);
+
+    let mut warnings = Vec::new();
+    let additional = additional_realtime_stops(
+        "8000105",
+        &realtime,
+        &planned_stops,
+        start,
+        end,
+        TransformMode::Strict,
+        &mut warnings,
+    );
+
+    assert_eq!(additional.len(), 1, "Only ICE 9590 is in the time frame");
+
+    let stop = additional[0]
+        .as_ref()
+        .expect("Failed to transform ICE 9590");
+
+    assert_eq!(stop.id, "-2312581224139581329-2310181230-101");
+    assert!(stop.added);
+    assert!(!stop.cancelled);
+    assert_eq!(stop.train_type, "ICE");
+    assert_eq!(stop.train_number, "9590");
+    assert_eq!(stop.real_platform.as_deref(), Some("8"));
+
+    let arrival = stop.arrival.as_ref().expect("ICE 9590 has an arrival");
+    assert_eq!(arrival.origin, "Köln Hbf");
+    assert_eq!(arrival.real_time, Some(arrival.planned_time));
+
+    let departure = stop.departure.as_ref().expect("ICE 9590 has a departure");
+    assert_eq!(departure.direction, "Fulda");
+    assert_eq!(
+        departure.planned_time,
+        Berlin.with_ymd_and_hms(2023, 10, 18, 13, 22, 0).unwrap()
+    );
+
+    assert_eq!(
+        stop.route
+            .iter()
+            .map(|stop| stop.name.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "Köln Hbf",
+            "Siegburg/Bonn",
+            "Frankfurt(M) Flughafen Fernbf",
+            "Frankfurt(Main)Hbf",
+            "Hanau Hbf",
+            "Fulda"
+        ]
+    );
+    assert!(stop.route.iter().all(|stop| stop.added));
+}
+
+#[test]
+fn connections_are_transformed() {
+    let (_, planned_stops) = fixtures();
+
+    let stop = planned_stops
+        .iter()
+        .find(|stop| stop.train_number == "1011")
+        .expect("ICE 1011 is in the plan");
+
+    assert_eq!(stop.connections.len(), 2);
+
+    let waiting = &stop.connections[0];
+    assert_eq!(waiting.status, ConnectionStatus::Waiting);
+    assert_eq!(waiting.train_type, "RE");
+    assert_eq!(waiting.train_number, "4570");
+    assert_eq!(waiting.line_indicator.as_deref(), Some("5"));
+    assert_eq!(waiting.planned_platform.as_deref(), Some("20"));
+    assert_eq!(waiting.real_platform.as_deref(), Some("21"));
+    assert_eq!(
+        waiting.real_time,
+        Some(
+            Berlin
+                .with_ymd_and_hms(2023, 10, 18, 13, 9, 0)
+                .unwrap()
+                .fixed_offset()
+        )
+    );
+
+    let cannot_wait = &stop.connections[1];
+    assert_eq!(cannot_wait.status, ConnectionStatus::CannotWait);
+    assert_eq!(cannot_wait.train_number, "15421");
+    assert_eq!(cannot_wait.real_time, None);
+}
+
+#[test]
+fn reference_trips_are_transformed() {
+    let (_, planned_stops) = fixtures();
+
+    let stop = planned_stops
+        .iter()
+        .find(|stop| stop.train_number == "15519")
+        .expect("RB 15519 is in the plan");
+
+    assert!(stop.cancelled);
+
+    let reference_trip = stop
+        .reference_trip
+        .as_ref()
+        .expect("RB 15519 has a reference trip");
+
+    assert_eq!(reference_trip.id, "-3907434398453470123-2310181325");
+    assert!(!reference_trip.cancelled);
+    assert_eq!(reference_trip.category, "SEV");
+    assert_eq!(reference_trip.number, "15519");
+    assert_eq!(reference_trip.relation, ReferenceTripRelation::Start);
+    assert_eq!(reference_trip.start.name, "Frankfurt(Main)Hbf");
+    assert_eq!(reference_trip.end.eva, "8000150");
+    assert_eq!(reference_trip.end.index, 3);
+    assert_eq!(
+        reference_trip.end.planned_time,
+        Some(
+            Berlin
+                .with_ymd_and_hms(2023, 10, 18, 14, 5, 0)
+                .unwrap()
+                .fixed_offset()
+        )
+    );
+}
+
+#[test]
+fn malformed_stops_fail_in_strict_mode() {
+    let (plan, realtime) = (common::timetable(MALFORMED_PLAN), common::realtime());
+
+    let (start, end) = common::hour(13);
+
+    let result = from_iris_timetables(
+        "8000105",
+        vec![plan],
+        realtime,
+        start,
+        end,
+        TransformMode::Strict,
+    );
+
+    assert_eq!(
+        result,
+        Err(IrisTransformError::MissingTripLabel {
+            stop_id: String::from("4871204378012364851-2310181240-3")
+        })
+    );
+}
+
+#[test]
+fn malformed_stops_are_skipped_in_lenient_mode() {
+    let (plan, realtime) = (common::timetable(MALFORMED_PLAN), common::realtime());
+
+    let (start, end) = common::hour(13);
+
+    let station_board = from_iris_timetables(
+        "8000105",
+        vec![plan],
+        realtime,
+        start,
+        end,
+        TransformMode::Lenient,
+    )
+    .expect("Lenient mode does not fail on invalid stops");
+
+    assert_eq!(
+        station_board
+            .stops
+            .iter()
+            .map(|stop| stop.train_number.as_str())
+            .collect::<Vec<_>>(),
+        vec!["1011", "9590"]
+    );
+
+    assert_eq!(
+        station_board.warnings,
+        vec![
+            IrisTransformError::MissingTripLabel {
+                stop_id: String::from("4871204378012364851-2310181240-3")
+            },
+            IrisTransformError::MissingPlannedTime {
+                stop_id: String::from("8263451907365421984-2310181100-9")
+            },
+            IrisTransformError::InvalidDate {
+                date: String::from("2313991320")
+            },
+            IrisTransformError::MissingArrivalDeparture {
+                stop_id: String::from("2290147586430997711-2310181250-6")
+            },
+        ]
+    );
+}
+
+#[test]
+fn invalid_messages_are_skipped_in_lenient_mode() {
+    let plan = common::plan();
+    let realtime = common::timetable(&REALTIME.replace(r#"ts="2310181250""#, r#"ts="2313991250""#));
+
+    let (start, end) = common::hour(13);
+
+    let station_board = from_iris_timetables(
+        "8000105",
+        vec![plan.clone()],
+        realtime.clone(),
+        start,
+        end,
+        TransformMode::Lenient,
+    )
+    .expect("Lenient mode does not fail on invalid messages");
+
+    let stop = station_board
+        .stops
+        .iter()
+        .find(|stop| stop.train_number == "1011")
+        .expect("ICE 1011 is kept although one of its messages is invalid");
+
+    assert!(stop.messages.is_empty());
+    assert_eq!(stop.real_platform.as_deref(), Some("9"));
+    assert_eq!(
+        station_board.warnings,
+        vec![IrisTransformError::InvalidDate {
+            date: String::from("2313991250")
+        }]
+    );
+
+    let result = from_iris_timetables(
+        "8000105",
+        vec![plan],
+        realtime,
+        start,
+        end,
+        TransformMode::Strict,
+    );
+
+    assert_eq!(
+        result,
+        Err(IrisTransformError::InvalidDate {
+            date: String::from("2313991250")
+        })
+    );
+}
+
+#[test]
+fn meta_station_boards_are_merged() {
+    let plan = common::plan();
+    let mut realtime = common::realtime();
+    realtime.disruptions = common::recent_changes().disruptions;
+
+    let (start, end) = common::hour(13);
+
+    let board = |eva: &str| {
+        from_iris_timetables(
+            eva,
+            vec![plan.clone()],
+            realtime.clone(),
+            start,
+            end,
+            TransformMode::Strict,
+        )
+        .expect("Failed to transform station board")
+    };
+
+    let mut station_board = board("8000105");
+    let meta_station_board = board("8098105");
+
+    let stop_count = station_board.stops.len();
+    assert_eq!(station_board.disruptions.len(), 1);
+
+    station_board.merge(meta_station_board);
+
+    assert_eq!(station_board.station_eva, "8000105");
+    assert_eq!(station_board.stops.len(), stop_count * 2);
+    assert_eq!(
+        station_board.disruptions.len(),
+        1,
+        "Shared disruptions are only kept once"
+    );
+    assert_eq!(
+        station_board
+            .stops
+            .iter()
+            .filter(|stop| stop.station_eva == "8098105")
+            .count(),
+        stop_count
+    );
+    assert!(
+        station_board
+            .stops
+            .windows(2)
+            .all(|stops| stops[0].planned_time() <= stops[1].planned_time()),
+        "The merged stops are sorted by time"
+    );
+}
+
+#[tokio::test]
+async fn unavailable_meta_stations_are_skipped() {
+    let source = FixtureSource::default()
+        .with_stations("8000105", common::stations(common::STATION))
+        .with_plan("8000105", "231018", "13", common::plan())
+        .with_realtime("8000105", common::realtime())
+        .with_plan("8098105", "231018", "13", common::plan())
+        .with_realtime("8098105", common::realtime());
+
+    let (start, end) = common::hour(13);
+
+    let station_board = meta_station_board_from(&source, "8000105", start, end)
+        .await
+        .expect("The station board is built although a meta station is unavailable");
+
+    assert!(station_board
+        .stops
+        .iter()
+        .any(|stop| stop.station_eva == "8098105"));
+    assert!(station_board.warnings.is_empty());
+    assert_eq!(
+        station_board
+            .unavailable_stations
+            .iter()
+            .map(|station| station.station.as_str())
+            .collect::<Vec<_>>(),
+        vec!["8089029"]
+    );
+}
+
+#[test]
+fn wings_are_resolved() {
+    let plan = common::timetable(WINGS_PLAN);
+    let realtime = common::timetable("<timetable station='Berlin Hbf'/>");
+
+    let (start, end) = common::hour(11);
+
+    let station_board = from_iris_timetables(
+        "8011160",
+        vec![plan],
+        realtime,
+        start,
+        end,
+        TransformMode::Strict,
+    )
+    .expect("Failed to transform station board");
+
+    let departure_wings = |train_number: &str| {
+        station_board
+            .stops
+            .iter()
+            .find(|stop| stop.train_number == train_number)
+            .and_then(|stop| stop.departure.as_ref())
+            .map(|departure| departure.wings.clone())
+            .expect("Train has a departure")
+    };
+
+    assert_eq!(
+        departure_wings("1"),
+        vec![Wing {
+            trip_id: String::from("-3821045768239146201-2310181120"),
+            stop_id: Some(String::from("-3821045768239146201-2310181120-3")),
+            train_type: Some(String::from("ICE")),
+            train_number: Some(String::from("11")),
+            line_indicator: Some(String::from("11")),
+            terminus: Some(String::from("Kassel-Wilhelmshöhe")),
+            position: Some(WingPosition::Rear),
+            split_join_station: Some(String::from("Hamm(Westf)Hbf")),
+        }]
+    );
+
+    assert_eq!(
+        departure_wings("11"),
+        vec![Wing {
+            trip_id: String::from("7351823904418290117-2310181120"),
+            stop_id: Some(String::from("7351823904418290117-2310181120-3")),
+            train_type: Some(String::from("ICE")),
+            train_number: Some(String::from("1")),
+            line_indicator: Some(String::from("1")),
+            terminus: Some(String::from("Köln Hbf")),
+            position: Some(WingPosition::Front),
+            split_join_station: Some(String::from("Hamm(Westf)Hbf")),
+        }],
+        "ICE 11 is linked back to ICE 1"
+    );
+
+    let unresolved = departure_wings("3311");
+    assert_eq!(unresolved.len(), 1);
+    assert_eq!(unresolved[0].trip_id, "9999999999999999999-2310181141");
+    assert_eq!(unresolved[0].stop_id, None, "The wing is not on the board");
+}
+
+#[test]
+fn wings_listing_each_other_have_no_position() {
+    let plan = common::timetable(&WINGS_PLAN.replace(
+        r#"<dp pt="2310181134" pp="12" ppth="Berlin-Spandau"#,
+        r#"<dp pt="2310181134" pp="12" wings="7351823904418290117-2310181120" ppth="Berlin-Spandau"#,
+    ));
+    let realtime = common::timetable("<timetable station='Berlin Hbf'/>");
+
+    let (start, end) = common::hour(11);
+
+    let station_board = from_iris_timetables(
+        "8011160",
+        vec![plan],
+        realtime,
+        start,
+        end,
+        TransformMode::Strict,
+    )
+    .expect("Failed to transform station board");
+
+    for train_number in ["1", "11"] {
+        let wings = station_board
+            .stops
+            .iter()
+            .find(|stop| stop.train_number == train_number)
+            .and_then(|stop| stop.departure.as_ref())
+            .map(|departure| departure.wings.clone())
+            .expect("Train has a departure");
+
+        assert_eq!(wings.len(), 1, "ICE {train_number} is not linked twice");
+        assert!(wings[0].stop_id.is_some());
+        assert_eq!(wings[0].position, None);
+    }
+}
+
+#[test]
+fn wings_are_resolved_across_meta_stations() {
+    let plan = common::timetable(WINGS_PLAN);
+    let realtime = common::timetable("<timetable station='Berlin Hbf'/>");
+
+    let (start, end) = common::hour(11);
+
+    let board = |eva: &str, train_number: &str| {
+        let mut plan = plan.clone();
+        plan.stops.retain(|stop| {
+            stop.trip_label
+                .as_ref()
+                .is_some_and(|trip_label| trip_label.train_number == train_number)
+        });
+
+        from_iris_timetables(
+            eva,
+            vec![plan],
+            realtime.clone(),
+            start,
+            end,
+            TransformMode::Strict,
+        )
+        .expect("Failed to transform station board")
+    };
+
+    let mut station_board = board("8011160", "1");
+    let meta_station_board = board("8098160", "11");
+
+    assert_eq!(
+        station_board.stops[0].departure.as_ref().unwrap().wings[0].stop_id,
+        None,
+        "ICE 11 is not on the station board of the main station"
+    );
+
+    station_board.merge(meta_station_board);
+
+    let departure_wings = |train_number: &str| {
+        station_board
+            .stops
+            .iter()
+            .find(|stop| stop.train_number == train_number)
+            .and_then(|stop| stop.departure.as_ref())
+            .map(|departure| departure.wings.clone())
+            .expect("Train has a departure")
+    };
+
+    let wings = departure_wings("1");
+    assert_eq!(wings.len(), 1);
+    assert_eq!(
+        wings[0].stop_id.as_deref(),
+        Some("-3821045768239146201-2310181120-3")
+    );
+    assert_eq!(wings[0].position, Some(WingPosition::Rear));
+
+    let wings = departure_wings("11");
+    assert_eq!(wings.len(), 1, "ICE 11 is linked back to ICE 1");
+    assert_eq!(wings[0].position, Some(WingPosition::Front));
+}
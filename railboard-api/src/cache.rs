@@ -3,7 +3,9 @@ use std::sync::Arc;
 use crate::vendo::location_search::LocationSearchCache;
 use chrono::TimeZone;
 use chrono_tz::Europe::Berlin;
+use iris_client::journey::IrisJourney;
 use iris_client::station_board::response::TimeTable;
+use iris_client::station_lookup::IrisStation;
 use redis::JsonAsyncCommands;
 use ris_client::journey_details::RisJourneyDetails;
 use ris_client::station_board::RisStationBoard;
@@ -13,8 +15,10 @@ use ris_client::{
 };
 use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
+use vendo_client::connection_search::VendoConnectionSearch;
 use vendo_client::journey_details::VendoJourneyDetails;
-use vendo_client::station_board::VendoStationBoard;
+use vendo_client::nearby::VendoNearbyLocation;
+use vendo_client::station_board::{VendoStationBoard, VendoTransportType};
 
 #[async_trait::async_trait]
 pub trait Cache: Sync + Send {
@@ -112,7 +116,7 @@ impl Cache for RedisCache {
             .ignore()
             .expire(&key, expiration)
             .ignore()
-            .query_async(&mut connection)
+            .query_async::<_, ()>(&mut connection)
             .await?;
         Ok(())
     }
@@ -127,19 +131,75 @@ pub trait CachableObject {
     ) -> Result<(), CacheInsertError>;
 }
 
+/// The part of the cache key of a vendo station board identifying its transport types, `None` being all of them.
+pub fn transport_types_key(transport_types: Option<&[VendoTransportType]>) -> String {
+    let mut names: Vec<&str> = transport_types
+        .unwrap_or(&VendoTransportType::ALL)
+        .iter()
+        .map(VendoTransportType::short_name)
+        .collect();
+
+    names.sort_unstable();
+    names.dedup();
+
+    if names.len() == VendoTransportType::ALL.len() {
+        String::from("all")
+    } else {
+        names.join(",")
+    }
+}
+
 #[async_trait::async_trait]
 impl CachableObject for VendoStationBoard {
     async fn insert_to_cache<C: Cache>(
         &self,
         cache: &C,
-        _information: Option<&str>,
+        information: Option<&str>,
     ) -> Result<(), CacheInsertError> {
-        let key = format!("vendo.station-board.{}.{}.{}", self.id, self.day, self.time);
+        let transport_types = transport_types_key(Some(&self.transport_types));
+
+        // Pages requested by cursor are keyed by their direction and cursor instead of their date
+        let key = match information {
+            Some(information) => format!(
+                "vendo.station-board.{}.{}.{}",
+                self.id, transport_types, information
+            ),
+            None => format!(
+                "vendo.station-board.{}.{}.{}.{}",
+                self.id, transport_types, self.day, self.time
+            ),
+        };
 
         cache.insert_to_cache(key, self, 90).await
     }
 }
 
+#[async_trait::async_trait]
+impl CachableObject for VendoConnectionSearch {
+    async fn insert_to_cache<C: Cache>(
+        &self,
+        cache: &C,
+        information: Option<&str>,
+    ) -> Result<(), CacheInsertError> {
+        let key = format!("vendo.connections.{}", information.unwrap_or(""));
+
+        cache.insert_to_cache(key, self, 60).await
+    }
+}
+
+#[async_trait::async_trait]
+impl CachableObject for Vec<VendoNearbyLocation> {
+    async fn insert_to_cache<C: Cache>(
+        &self,
+        cache: &C,
+        information: Option<&str>,
+    ) -> Result<(), CacheInsertError> {
+        let key = format!("vendo.nearby.{}", information.unwrap_or(""));
+
+        cache.insert_to_cache(key, self, 60 * 60 * 24).await
+    }
+}
+
 #[async_trait::async_trait]
 impl CachableObject for LocationSearchCache {
     async fn insert_to_cache<C: Cache>(
@@ -192,6 +252,32 @@ impl CachableObject for (TimeTable, String) {
     }
 }
 
+#[async_trait::async_trait]
+impl CachableObject for IrisJourney {
+    async fn insert_to_cache<C: Cache>(
+        &self,
+        cache: &C,
+        _information: Option<&str>,
+    ) -> Result<(), CacheInsertError> {
+        let key = format!("iris.journey.{}", self.trip_id);
+
+        cache.insert_to_cache(key, &self, 60).await
+    }
+}
+
+#[async_trait::async_trait]
+impl CachableObject for Vec<IrisStation> {
+    async fn insert_to_cache<C: Cache>(
+        &self,
+        cache: &C,
+        information: Option<&str>,
+    ) -> Result<(), CacheInsertError> {
+        let key = format!("iris.station.{}", information.unwrap_or(""));
+
+        cache.insert_to_cache(key, &self, 60 * 60 * 24).await
+    }
+}
+
 #[async_trait::async_trait]
 impl CachableObject for (String, String, RisJourneySearchResponse) {
     async fn insert_to_cache<C: Cache>(
@@ -235,13 +321,14 @@ impl CachableObject for RisStationBoard {
     async fn insert_to_cache<C: Cache>(
         &self,
         cache: &C,
-        _information: Option<&str>,
+        information: Option<&str>,
     ) -> Result<(), CacheInsertError> {
         let key = format!(
-            "ris.station-board.{}.{}.{}",
+            "ris.station-board.{}.{}.{}.{}",
             self.eva,
             self.time_start.naive_utc().format("%Y-%m-%dT%H:%M"),
-            self.time_end.naive_utc().format("%Y-%m-%dT%H:%M")
+            self.time_end.naive_utc().format("%Y-%m-%dT%H:%M"),
+            information.unwrap_or("time-start")
         );
 
         cache.insert_to_cache(key, &self, 180).await
@@ -4,11 +4,15 @@ use axum::{routing::get, Router};
 
 use crate::SharedState;
 
+pub mod operators;
 pub mod station_board;
 pub mod station_board_v2;
 
 pub fn router_v1() -> Router<Arc<SharedState>> {
-    Router::new().route("/station_board/:id", get(station_board::station_board))
+    Router::new()
+        .route("/station_board/:id", get(station_board::station_board))
+        .route("/operators", get(operators::operators))
+        .route("/operators/:id", get(operators::operator))
 }
 
 pub fn router_v2() -> Router<Arc<SharedState>> {
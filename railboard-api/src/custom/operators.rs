@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Path, Query},
+    Json,
+};
+use serde::Deserialize;
+
+use ris_client::operators::{self, RisOperator};
+
+use crate::error::{ErrorDomain, RailboardApiError, RailboardResult};
+
+#[derive(Deserialize)]
+pub struct OperatorsQuery {
+    pub query: Option<String>,
+}
+
+#[utoipa::path(
+get,
+path = "/v1/operators",
+params(
+("query" = Option < String >, Query, description = "Only list operators whose operator code, short name or name contains this (for example: \"metronom\")")
+),
+tag = "Custom",
+responses(
+(status = 200, description = "The operators of the bundled operator registry", body = [RisOperator])
+)
+)]
+pub async fn operators(Query(query): Query<OperatorsQuery>) -> Json<Vec<RisOperator>> {
+    let operators = match query.query {
+        Some(query) => operators::search_operators(&query)
+            .into_iter()
+            .cloned()
+            .collect(),
+        None => operators::operators().to_vec(),
+    };
+
+    Json(operators)
+}
+
+#[utoipa::path(
+get,
+path = "/v1/operators/{id}",
+params(
+("id" = String, Path, description = "The administration id or operator code of the operator you are looking for (for example: \"80\" or \"ME\")")
+),
+tag = "Custom",
+responses(
+(status = 200, description = "The operator with the administration id or operator code, the first one of the registry if several operators share the code", body = RisOperator),
+(status = 400, description = "The Error returned if there is no such operator, will be the Input Domain", body = RailboardApiError)
+)
+)]
+pub async fn operator(Path(id): Path<String>) -> RailboardResult<Json<RisOperator>> {
+    operators::operator_by_administration_id(&id)
+        .or_else(|| operators::operator_by_code(&id))
+        .cloned()
+        .map(Json)
+        .ok_or_else(|| RailboardApiError {
+            domain: ErrorDomain::Input,
+            message: format!(
+                "There is no operator with the administration id or operator code {id}"
+            ),
+            error: None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::extract::Path;
+
+    use super::operator;
+
+    #[tokio::test]
+    async fn operators_are_looked_up_by_code() {
+        let db = operator(Path(String::from("DB")))
+            .await
+            .expect("DB is not found by its operator code");
+        assert_eq!(db.administration_id.as_deref(), Some("80"));
+
+        let obb = operator(Path(String::from("obb")))
+            .await
+            .expect("ÖBB is not found by its operator code");
+        assert_eq!(obb.short_name, "ÖBB");
+
+        let metronom = operator(Path(String::from("ME")))
+            .await
+            .expect("metronom is not found by its operator code");
+        assert_eq!(metronom.short_name, "metronom");
+
+        assert!(operator(Path(String::from("87"))).await.is_ok());
+        assert!(operator(Path(String::from("XYZ"))).await.is_err());
+    }
+}
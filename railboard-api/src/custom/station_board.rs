@@ -8,7 +8,8 @@ use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
 use chrono_tz::Europe::Berlin;
 use serde::{Deserialize, Serialize};
 
-use iris_client::station_board::{message::Message, IrisStationBoard, RouteStop};
+use iris_client::station_board::{message::Message, wing::Wing, IrisStationBoard, RouteStop};
+use ris_client::station_board::ExpandTimeFrame;
 use utoipa::ToSchema;
 
 use crate::{error::RailboardResult, iris::station_board::iris_station_board, SharedState};
@@ -53,14 +54,18 @@ pub async fn station_board(
     };
 
     let (ris_station_board, iris_station_board) = tokio::join!(
-        state
-            .ris_client
-            .station_board(&eva, Some(time_start), Some(time_end),),
+        state.ris_client.station_board(
+            &eva,
+            Some(time_start),
+            Some(time_end),
+            ExpandTimeFrame::TimeStart
+        ),
         iris_station_board(
             &eva,
             time_end,
             time_start,
             state.iris_client.clone(),
+            &state.iris_realtime_store,
             &state.cache
         )
     );
@@ -71,6 +76,8 @@ pub async fn station_board(
         station_eva: String::new(),
         stops: vec![],
         disruptions: vec![],
+        warnings: vec![],
+        unavailable_stations: vec![],
     });
 
     let items = ris_station_board.items;
@@ -322,5 +329,5 @@ pub struct DepartureArrival {
     #[schema(nullable)]
     pub time_type: Option<String>,
 
-    pub wings: Vec<String>,
+    pub wings: Vec<Wing>,
 }
@@ -8,7 +8,7 @@ use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
 use chrono_tz::Europe::Berlin;
 use serde::{Deserialize, Serialize};
 
-use iris_client::station_board::{message::Message, IrisStationBoard, RouteStop};
+use iris_client::station_board::{message::Message, wing::Wing, IrisStationBoard, RouteStop};
 use utoipa::ToSchema;
 
 use crate::{error::RailboardResult, iris::station_board::iris_station_board, SharedState};
@@ -47,12 +47,13 @@ pub async fn station_board_v2(
     let time_end = Berlin.from_utc_datetime(&(time_start.naive_utc() + chrono::Duration::hours(1)));
 
     let (vendo_station_board, iris_station_board) = tokio::join!(
-        state.vendo_client.station_board(&eva, time_start),
+        state.vendo_client.station_board(&eva, time_start, None),
         iris_station_board(
             &eva,
             time_end,
             time_start,
             state.iris_client.clone(),
+            &state.iris_realtime_store,
             &state.cache
         )
     );
@@ -63,6 +64,8 @@ pub async fn station_board_v2(
         station_eva: String::new(),
         stops: vec![],
         disruptions: vec![],
+        warnings: vec![],
+        unavailable_stations: vec![],
     });
 
     let items = vendo_station_board.station_board;
@@ -323,5 +326,5 @@ pub struct DepartureArrival {
     pub time_scheduled: DateTime<FixedOffset>,
     pub time_realtime: Option<DateTime<FixedOffset>>,
 
-    pub wings: Vec<String>,
+    pub wings: Vec<Wing>,
 }
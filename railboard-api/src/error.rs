@@ -80,6 +80,11 @@ impl From<VendoOrRequestError> for RailboardApiError {
                 message: format!("Failed to get from Vendo: {err}"),
                 error: Some(UnderlyingApiError::Vendo(err)),
             },
+            VendoOrRequestError::InvalidCursor { .. } => RailboardApiError {
+                domain: ErrorDomain::Input,
+                message: value.to_string(),
+                error: None,
+            },
         }
     }
 }
@@ -102,6 +107,26 @@ impl From<IrisOrRequestError> for RailboardApiError {
                 message: format!("Got invalid/unrecognized xml from Iris: {err}"),
                 error: None,
             },
+            IrisOrRequestError::MalformedXML(err) => RailboardApiError {
+                domain: ErrorDomain::Iris,
+                message: format!("Got invalid/unrecognized xml from Iris: {err}"),
+                error: None,
+            },
+            IrisOrRequestError::InvalidData(err) => RailboardApiError {
+                domain: ErrorDomain::Iris,
+                message: format!("Got invalid data from Iris: {err}"),
+                error: None,
+            },
+            IrisOrRequestError::InvalidTripId { .. } => RailboardApiError {
+                domain: ErrorDomain::Input,
+                message: value.to_string(),
+                error: None,
+            },
+            IrisOrRequestError::TripNotFound { .. } => RailboardApiError {
+                domain: ErrorDomain::Iris,
+                message: value.to_string(),
+                error: None,
+            },
         }
     }
 }
@@ -134,6 +159,11 @@ impl From<RisOrRequestError> for RailboardApiError {
                 message: "There was nothing found with these parameters".to_string(),
                 error: None,
             },
+            RisOrRequestError::UnsupportedBackend(err) => RailboardApiError {
+                domain: ErrorDomain::Ris,
+                message: err.to_string(),
+                error: None,
+            },
         }
     }
 }
@@ -4,8 +4,16 @@ use axum::{routing::get, Router};
 
 use crate::SharedState;
 
+pub mod disruptions;
+pub mod journey;
+pub mod source;
 pub mod station_board;
+pub mod station_lookup;
 
 pub fn router() -> Router<Arc<SharedState>> {
-    Router::new().route("/station_board/:id", get(station_board::station_board))
+    Router::new()
+        .route("/station_board/:id", get(station_board::station_board))
+        .route("/station/:query", get(station_lookup::station_lookup))
+        .route("/journey/:trip_id", get(journey::journey))
+        .route("/disruptions/:eva", get(disruptions::disruptions))
 }
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+use iris_client::{
+    disruptions::{from_realtime_timetable, IrisStationDisruptions},
+    source::IrisSource,
+};
+
+use crate::{error::RailboardResult, SharedState};
+
+use super::source::CachedIrisSource;
+
+#[utoipa::path(
+get,
+path = "/iris/v1/disruptions/{eva}",
+params(
+("eva" = String, Path, description = "The eva number of the Station you are requesting"),
+),
+tag = "Iris",
+responses(
+(status = 200, description = "The active and upcoming disruptions of the Station", body = IrisStationDisruptions),
+(status = 400, description = "The Error returned by Iris, will be the Iris Domain with UnderlyingApiError Variant 2, which has no Information because Iris doesn't return errors", body = RailboardApiError),
+(status = 500, description = "The Error returned if the request or deserialization fails", body = RailboardApiError)
+)
+)]
+pub async fn disruptions(
+    Path(eva): Path<String>,
+    State(state): State<Arc<SharedState>>,
+) -> RailboardResult<Json<IrisStationDisruptions>> {
+    let realtime = CachedIrisSource::new(&state).realtime(&eva).await?;
+
+    Ok(Json(from_realtime_timetable(
+        &eva,
+        realtime,
+        chrono::Utc::now(),
+    )))
+}
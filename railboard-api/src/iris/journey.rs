@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use iris_client::journey::{journey_from, parse_trip_id, IrisJourney};
+
+use crate::{
+    cache::{CachableObject, Cache},
+    error::RailboardResult,
+    SharedState,
+};
+
+use super::source::CachedIrisSource;
+
+#[derive(Deserialize, IntoParams)]
+pub struct IrisJourneyQuery {
+    /// The eva number of a station the train stops at, the journey is reconstructed starting from this station
+    pub eva: String,
+}
+
+#[utoipa::path(
+get,
+path = "/iris/v1/journey/{trip_id}",
+params(
+("trip_id" = String, Path, description = "The trip id (or the id of a stop of the trip) of the train, e.g. -7874571842864554321-1403311221"),
+IrisJourneyQuery
+),
+tag = "Iris",
+responses(
+(status = 200, description = "The reconstructed Journey", body = IrisJourney),
+(status = 400, description = "The Error returned by Iris, will be the Iris Domain if Iris has no stop of the trip at the station, or the Input Domain if the trip id is invalid", body = RailboardApiError),
+(status = 500, description = "The Error returned if the request or deserialization fails", body = RailboardApiError)
+)
+)]
+pub async fn journey(
+    Path(trip_id): Path<String>,
+    Query(params): Query<IrisJourneyQuery>,
+    State(state): State<Arc<SharedState>>,
+) -> RailboardResult<Json<IrisJourney>> {
+    let cache_id = parse_trip_id(&trip_id)
+        .map(|(trip_id, _)| trip_id)
+        .unwrap_or_else(|| trip_id.to_owned());
+
+    if let Some(cached) = state
+        .cache
+        .get_from_id(&format!("iris.journey.{}", cache_id))
+        .await
+    {
+        return Ok(Json(cached));
+    }
+
+    let journey = journey_from(&CachedIrisSource::new(&state), &trip_id, &params.eva).await?;
+
+    {
+        let journey = journey.clone();
+
+        tokio::spawn(async move { journey.insert_to_cache(&state.cache, None).await });
+    }
+
+    Ok(Json(journey))
+}
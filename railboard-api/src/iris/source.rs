@@ -0,0 +1,109 @@
+use iris_client::{
+    realtime_store::RealtimeStore, source::IrisSource, station_board::response::TimeTable,
+    station_lookup::IrisStation, IrisClient, IrisOrRequestError,
+};
+
+use crate::{
+    cache::{self, CachableObject, Cache},
+    SharedState,
+};
+
+/// Fetches the data of IRIS through the cache, realtime information is kept up to date by the [`RealtimeStore`].
+pub struct CachedIrisSource<'a> {
+    pub iris_client: &'a IrisClient,
+    pub realtime_store: &'a RealtimeStore,
+    pub cache: &'a cache::RedisCache,
+}
+
+impl<'a> CachedIrisSource<'a> {
+    pub fn new(state: &'a SharedState) -> Self {
+        Self {
+            iris_client: &state.iris_client,
+            realtime_store: &state.iris_realtime_store,
+            cache: &state.cache,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IrisSource for CachedIrisSource<'_> {
+    async fn planned(
+        &self,
+        eva: &str,
+        date: &str,
+        hour: &str,
+    ) -> Result<TimeTable, IrisOrRequestError> {
+        if let Some(cached) = self
+            .cache
+            .get_from_id::<TimeTable>(&format!(
+                "iris.station-board.plan.{}.{}.{}",
+                eva, date, hour
+            ))
+            .await
+        {
+            return Ok(cached);
+        }
+
+        let timetable = self
+            .iris_client
+            .planned_station_board(eva, date, hour)
+            .await?;
+
+        {
+            let cache_timetable = (
+                timetable.clone(),
+                eva.to_owned(),
+                date.to_owned(),
+                hour.to_owned(),
+            );
+            let cache = self.cache.clone();
+
+            tokio::spawn(async move { cache_timetable.insert_to_cache(&cache, None).await });
+        }
+
+        Ok(timetable)
+    }
+
+    async fn realtime(&self, eva: &str) -> Result<TimeTable, IrisOrRequestError> {
+        if let Some(cached) = self
+            .cache
+            .get_from_id::<TimeTable>(&format!("iris.station-board.realtime.{}", eva))
+            .await
+        {
+            return Ok(cached);
+        }
+
+        let realtime = self.realtime_store.realtime(self.iris_client, eva).await?;
+
+        {
+            let cache_realtime = (realtime.clone(), eva.to_owned());
+            let cache = self.cache.clone();
+
+            tokio::spawn(async move { cache_realtime.insert_to_cache(&cache, None).await });
+        }
+
+        Ok(realtime)
+    }
+
+    async fn stations(&self, pattern: &str) -> Result<Vec<IrisStation>, IrisOrRequestError> {
+        if let Some(cached) = self
+            .cache
+            .get_from_id(&format!("iris.station.{}", pattern))
+            .await
+        {
+            return Ok(cached);
+        }
+
+        let stations = self.iris_client.station_lookup(pattern).await?;
+
+        {
+            let stations = stations.clone();
+            let pattern = pattern.to_owned();
+            let cache = self.cache.clone();
+
+            tokio::spawn(async move { stations.insert_to_cache(&cache, Some(&pattern)).await });
+        }
+
+        Ok(stations)
+    }
+}
@@ -2,23 +2,26 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
+    http::{header::ACCEPT_LANGUAGE, HeaderMap},
     Json,
 };
-use chrono::{DateTime, Duration, FixedOffset, TimeZone, Timelike};
+use chrono::{DateTime, FixedOffset, TimeZone};
 use chrono_tz::{Europe::Berlin, Tz};
 use serde::Deserialize;
 use utoipa::IntoParams;
 
 use iris_client::{
-    station_board::{from_iris_timetable, response::TimeTable, IrisStationBoard},
-    IrisClient, IrisOrRequestError,
+    realtime_store::RealtimeStore,
+    station_board::{
+        message::MessageLanguage, meta_station_board_from, station_board_from, IrisStationBoard,
+        StationBoardEventFilter, StationBoardFilter,
+    },
+    IrisClient,
 };
 
-use crate::{
-    cache::{self, CachableObject, Cache},
-    error::RailboardResult,
-    SharedState,
-};
+use crate::{cache, error::RailboardResult, SharedState};
+
+use super::source::CachedIrisSource;
 
 #[derive(Deserialize, IntoParams)]
 pub struct IrisStationBoardQuery {
@@ -28,6 +31,50 @@ pub struct IrisStationBoardQuery {
     pub lookbehind: Option<u32>,
     /// The time to request data for in the future
     pub lookahead: Option<u32>,
+    /// Whether to include the stops of the meta stations of the station (e.G. the S-Bahn platforms of Frankfurt(Main)Hbf)
+    pub meta: Option<bool>,
+    /// The language of the message texts, takes precedence over the `Accept-Language` header. Defaults to German.
+    pub lang: Option<MessageLanguage>,
+    /// Comma separated train types to include, e.G. `ICE,IC`
+    pub train_type: Option<String>,
+    /// Comma separated lines to include, e.G. `S1,S8` or `58`
+    pub line: Option<String>,
+    /// Comma separated platforms to include
+    pub platform: Option<String>,
+    /// Only include trains whose route passes through or ends at a station containing this name, \
+    /// after the station for departures and before it for arrivals
+    pub via: Option<String>,
+    /// Only include arrivals or departures
+    pub events: Option<StationBoardEventFilter>,
+    /// Whether to remove stops IRIS marks as hidden. Defaults to false.
+    pub hide_hidden: Option<bool>,
+    /// Whether to remove cancelled stops. Defaults to false.
+    pub hide_cancelled: Option<bool>,
+}
+
+impl IrisStationBoardQuery {
+    fn filter(&self) -> StationBoardFilter {
+        StationBoardFilter {
+            train_types: split_list(self.train_type.as_deref()),
+            lines: split_list(self.line.as_deref()),
+            platforms: split_list(self.platform.as_deref()),
+            via: self.via.to_owned().filter(|via| !via.trim().is_empty()),
+            events: self.events,
+            hide_hidden: self.hide_hidden.unwrap_or(false),
+            hide_cancelled: self.hide_cancelled.unwrap_or(false),
+        }
+    }
+}
+
+fn split_list(list: Option<&str>) -> Vec<String> {
+    list.map(|list| {
+        list.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(ToOwned::to_owned)
+            .collect()
+    })
+    .unwrap_or_default()
 }
 
 #[utoipa::path(
@@ -35,6 +82,7 @@ get,
 path = "/iris/v1/station_board/{eva}",
 params(
 ("eva" = String, Path, description = "The eva number of the Station you are requesting"),
+("Accept-Language" = Option<String>, Header, description = "The preferred language of the message texts (de or en), ignored if `lang` is set"),
 IrisStationBoardQuery
 ),
 tag = "Iris",
@@ -47,6 +95,7 @@ responses(
 pub async fn station_board(
     Path(eva): Path<String>,
     Query(params): Query<IrisStationBoardQuery>,
+    headers: HeaderMap,
     State(state): State<Arc<SharedState>>,
 ) -> RailboardResult<Json<IrisStationBoard>> {
     let lookbehind = params.lookbehind.unwrap_or(20);
@@ -61,148 +110,98 @@ pub async fn station_board(
     let lookbehind = date - chrono::Duration::minutes(lookbehind as i64);
     let lookahead = date + chrono::Duration::minutes(lookahead as i64);
 
-    let station_board = iris_station_board(
-        &eva,
-        lookahead,
-        lookbehind,
-        state.iris_client.clone(),
-        &state.cache,
-    )
-    .await?;
+    let language = params.lang.unwrap_or_else(|| {
+        headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|header| header.to_str().ok())
+            .and_then(accept_language)
+            .unwrap_or_default()
+    });
+
+    let mut station_board = if params.meta.unwrap_or(false) {
+        iris_meta_station_board(
+            &eva,
+            lookahead,
+            lookbehind,
+            state.iris_client.clone(),
+            &state.iris_realtime_store,
+            &state.cache,
+        )
+        .await?
+    } else {
+        iris_station_board(
+            &eva,
+            lookahead,
+            lookbehind,
+            state.iris_client.clone(),
+            &state.iris_realtime_store,
+            &state.cache,
+        )
+        .await?
+    };
+
+    station_board.localize(language);
+    station_board.filter(&params.filter());
 
     Ok(Json(station_board))
 }
 
-pub async fn iris_station_board(
+/// Picks the supported language with the highest quality from an `Accept-Language` header.
+fn accept_language(header: &str) -> Option<MessageLanguage> {
+    let mut languages = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let language = MessageLanguage::from_language_tag(parts.next()?)?;
+            let quality = parts
+                .find_map(|part| part.trim().strip_prefix("q="))
+                .and_then(|quality| quality.parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            Some((language, quality))
+        })
+        .collect::<Vec<_>>();
+
+    // stable sort, so languages with the same quality keep the order of the header
+    languages.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    languages
+        .into_iter()
+        .find(|(_, quality)| *quality > 0.0)
+        .map(|(language, _)| language)
+}
+
+/// Like [`iris_station_board`], but also merges the station boards of all meta stations of the station into it.
+pub async fn iris_meta_station_board(
     eva: &str,
     lookahead: DateTime<Tz>,
     lookbehind: DateTime<Tz>,
     iris_client: Arc<IrisClient>,
+    realtime_store: &RealtimeStore,
     cache: &cache::RedisCache,
 ) -> RailboardResult<IrisStationBoard> {
-    let mut dates = Vec::new();
-
-    for current_date in DateRange(lookbehind, lookahead) {
-        dates.push(current_date);
-    }
-
-    let (realtime, timetables) = tokio::join!(
-        get_realtime(iris_client.clone(), cache, eva),
-        futures::future::join_all(dates.iter().map(|date| async {
-            if let Some(cached) = cache
-                .get_from_id::<TimeTable>(&format!(
-                    "iris.station-board.plan.{}.{}.{}",
-                    eva,
-                    date.format("%Y-%m-%d"),
-                    date.format("%H")
-                ))
-                .await
-            {
-                return Ok(cached);
-            }
-            let timetable = iris_client
-                .as_ref()
-                .planned_station_board(
-                    eva,
-                    &date.format("%y%m%d").to_string(),
-                    &date.format("%H").to_string(),
-                )
-                .await;
-            match timetable {
-                Ok(timetable) => {
-                    let cache_timetable = (
-                        timetable.clone(),
-                        eva.to_string(),
-                        date.format("%Y-%m-%d").to_string(),
-                        date.format("%H").to_string(),
-                    );
-                    let cache = cache.clone();
-                    tokio::spawn(
-                        async move { cache_timetable.insert_to_cache(&cache, None).await },
-                    );
-                    Ok(timetable)
-                }
-                Err(err) => Err(err),
-            }
-        }))
-    );
-
-    let realtime = realtime?;
-    let timetables = timetables
-        .into_iter()
-        .filter_map(|result| result.ok())
-        .collect::<Vec<_>>();
-
-    let disruptions = realtime
-        .disruptions
-        .into_iter()
-        .map(|message| message.into())
-        .collect::<Vec<iris_client::station_board::message::Message>>();
-
-    let mut stops = Vec::new();
-
-    // TODO: find additional stops in realtime that are not in planned
-    // realtime.stops.iter().filter(|stop| todo!());
-
-    for timetable in timetables {
-        for stop in timetable.stops {
-            let realtime = realtime
-                .stops
-                .iter()
-                .find(|realtime_stop| realtime_stop.id == stop.id);
-            stops.push(from_iris_timetable(
-                eva,
-                &timetable.station_name,
-                stop,
-                realtime.map(|realtime| realtime.to_owned()),
-            ));
-        }
-    }
-
-    let station_board = IrisStationBoard {
-        station_name: realtime.station_name,
-        station_eva: String::from(eva),
-        disruptions,
-        stops,
+    let source = CachedIrisSource {
+        iris_client: iris_client.as_ref(),
+        realtime_store,
+        cache,
     };
 
-    Ok(station_board)
-}
-
-struct DateRange(DateTime<Tz>, DateTime<Tz>);
-
-impl Iterator for DateRange {
-    type Item = DateTime<Tz>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.0 <= self.1 || self.0.hour() == self.1.hour() {
-            let next = self.0 + Duration::hours(1);
-            Some(std::mem::replace(&mut self.0, next))
-        } else {
-            None
-        }
-    }
+    Ok(meta_station_board_from(&source, eva, lookbehind, lookahead).await?)
 }
 
-async fn get_realtime(
+pub async fn iris_station_board(
+    eva: &str,
+    lookahead: DateTime<Tz>,
+    lookbehind: DateTime<Tz>,
     iris_client: Arc<IrisClient>,
+    realtime_store: &RealtimeStore,
     cache: &cache::RedisCache,
-    id: &str,
-) -> Result<TimeTable, IrisOrRequestError> {
-    if let Some(cached) = &cache
-        .get_from_id::<TimeTable>(&format!("iris.station-board.realtime.{}", id.to_owned()))
-        .await
-    {
-        return Ok(cached.to_owned());
-    }
-    let realtime = iris_client.as_ref().realtime_station_board(id).await;
-
-    match realtime {
-        Ok(realtime) => {
-            let cache_realtime = (realtime.clone(), id.to_owned());
-            let cache = cache.clone();
-            tokio::spawn(async move { cache_realtime.insert_to_cache(&cache, None).await });
-            Ok(realtime)
-        }
-        Err(err) => Err(err),
-    }
+) -> RailboardResult<IrisStationBoard> {
+    let source = CachedIrisSource {
+        iris_client: iris_client.as_ref(),
+        realtime_store,
+        cache,
+    };
+
+    Ok(station_board_from(&source, eva, lookbehind, lookahead).await?)
 }
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+use iris_client::{source::IrisSource, station_lookup::IrisStation};
+
+use crate::{error::RailboardResult, SharedState};
+
+use super::source::CachedIrisSource;
+
+#[utoipa::path(
+get,
+path = "/iris/v1/station/{query}",
+params(
+("query" = String, Path, description = "The name, eva number or DS100 code of the Station you are looking for (for example: \"FF\")")
+),
+tag = "Iris",
+responses(
+(status = 200, description = "The Stations matching the query, empty if Iris knows no matching Station", body = [IrisStation]),
+(status = 400, description = "The Error returned by Iris, will be the Iris Domain with UnderlyingApiError Variant 2, which has no Information because Iris doesn't return errors", body = RailboardApiError),
+(status = 500, description = "The Error returned if the request or deserialization fails", body = RailboardApiError)
+)
+)]
+pub async fn station_lookup(
+    Path(query): Path<String>,
+    State(state): State<Arc<SharedState>>,
+) -> RailboardResult<Json<Vec<IrisStation>>> {
+    let stations = CachedIrisSource::new(&state).stations(&query).await?;
+
+    Ok(Json(stations))
+}
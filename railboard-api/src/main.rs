@@ -10,8 +10,8 @@ use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use iris_client::IrisClient;
-use ris_client::RisClient;
+use iris_client::{realtime_store::RealtimeStore, IrisClient};
+use ris_client::{RisBackend, RisBackendKind, RisBackends, RisClient};
 use vendo_client::VendoClient;
 
 use crate::cache::RedisCache;
@@ -30,7 +30,12 @@ paths(
 vendo::station_board::station_board,
 vendo::location_search::location_search,
 vendo::journey_details::journey_details,
+vendo::connections::connections,
+vendo::nearby::nearby,
 iris::station_board::station_board,
+iris::station_lookup::station_lookup,
+iris::journey::journey,
+iris::disruptions::disruptions,
 ris::journey_search::journey_search,
 ris::journey_details::journey_details,
 ris::station_board::station_board,
@@ -38,6 +43,8 @@ ris::station_information::station_information,
 ris::station_search_by_name::station_search_by_name,
 custom::station_board::station_board,
 custom::station_board_v2::station_board_v2,
+custom::operators::operators,
+custom::operators::operator,
 ),
 components(schemas(
 error::RailboardApiError,
@@ -49,25 +56,61 @@ vendo_client::shared::Time,
 vendo_client::shared::Notice,
 vendo_client::shared::HimNotice,
 vendo_client::shared::Attribute,
+vendo_client::shared::PagingDirection,
+vendo_client::shared::VendoDemand,
+vendo_client::shared::VendoDemandClass,
+vendo_client::shared::VendoDemandLevel,
 vendo_client::station_board::VendoStationBoard,
+vendo_client::station_board::VendoTransportType,
 vendo_client::station_board::StationBoardElement,
 vendo_client::station_board::StationBoardArrival,
 vendo_client::station_board::StationBoardDeparture,
 vendo_client::location_search::VendoLocationSearchResult,
 vendo_client::location_search::VendoLocationSearchCoordinates,
+vendo_client::location_search::VendoLocationType,
+vendo_client::nearby::VendoNearbyLocation,
 vendo_client::journey_details::VendoJourneyDetails,
 vendo_client::journey_details::VendoTrainSchedule,
+vendo_client::connection_search::VendoConnectionSearch,
+vendo_client::connection_search::VendoConnection,
+vendo_client::connection_search::VendoConnectionSectionType,
+vendo_client::connection_search::VendoConnectionSection,
+vendo_client::connection_search::VendoConnectionLocation,
+vendo_client::connection_search::VendoConnectionStop,
+vendo_client::connection_search::VendoTransfer,
 vendo_client::journey_details::VendoStop,
 // Iris stuff
+iris_client::IrisTransformError,
+iris_client::IrisUnavailableStation,
 iris_client::station_board::IrisStationBoard,
 iris_client::station_board::StationBoardStop,
 iris_client::station_board::StationBoardStopArrival,
 iris_client::station_board::StationBoardStopDeparture,
 iris_client::station_board::RouteStop,
 iris_client::station_board::ReplacedTrain,
+iris_client::station_board::wing::Wing,
+iris_client::station_board::wing::WingPosition,
+iris_client::station_board::connection::StationBoardConnection,
+iris_client::station_board::connection::ConnectionStatus,
+iris_client::station_board::reference::ReferenceTrip,
+iris_client::station_board::reference::ReferenceTripStop,
+iris_client::station_board::reference::ReferenceTripRelation,
 iris_client::station_board::message::Message,
 iris_client::station_board::message::MessageStatus,
 iris_client::station_board::message::MessagePriority,
+iris_client::station_board::message::MessageLanguage,
+iris_client::station_board::message::MessageCodeCategory,
+iris_client::station_board::message::MessageSeverity,
+iris_client::station_board::StationBoardEventFilter,
+iris_client::station_board::StationDisruption,
+iris_client::station_board::DisruptionTrip,
+iris_client::station_board::DisruptionDistributorMessage,
+iris_client::station_board::DisruptionDistributorType,
+iris_client::disruptions::IrisStationDisruptions,
+iris_client::station_lookup::IrisStation,
+iris_client::journey::IrisJourney,
+iris_client::journey::IrisJourneyStop,
+iris_client::journey::IrisJourneyStopEvent,
 // Ris stuff
 ris_client::RisError,
 ris_client::RisUnauthorizedError,
@@ -87,6 +130,7 @@ ris_client::station_board::RisStationBoard,
 ris_client::station_board::RisStationBoardItem,
 ris_client::station_board::RisStationBoardItemAdministration,
 ris_client::station_board::DepartureArrival,
+ris_client::station_board::ExpandTimeFrame,
 ris_client::station_information::RisStationInformation,
 ris_client::station_information::RisStationNameContent,
 ris_client::station_information::RisPosition,
@@ -94,6 +138,7 @@ ris_client::station_search::RisStationSearchResponse,
 ris_client::station_search::RisStationSearchElement,
 ris_client::station_search::RisStationSearchTranslatable,
 ris_client::station_search::RisStationSearchNameContent,
+ris_client::operators::RisOperator,
 // Custom stuff
 custom::station_board::StationBoard,
 custom::station_board::StationBoardItem,
@@ -141,22 +186,16 @@ async fn main() {
 
     let redis_client = Arc::new(redis_client);
 
-    let ris_api_key = std::env::var("RIS_API_KEY").expect("RIS_API_KEY env variable is not set");
-    let ris_client_id =
-        std::env::var("RIS_CLIENT_ID").expect("RIS_CLIENT_ID env variable is not set");
-
     let http_client = Client::builder()
         // .add_root_certificate(Certificate::from_pem(include_bytes!("../../mitm.pem")).unwrap())
         // .proxy(Proxy::all("http://localhost:8080").unwrap())
         .build()
         .unwrap();
 
-    let ris_client = Arc::new(RisClient::new(
+    let ris_client = Arc::new(RisClient::with_backends(
         Some(http_client.clone()),
         None,
-        None,
-        &ris_client_id,
-        &ris_api_key,
+        ris_backends(),
     ));
 
     let iris_client = Arc::new(IrisClient::new(Some(http_client.clone()), None, None));
@@ -174,6 +213,7 @@ async fn main() {
             vendo_client,
             ris_client,
             iris_client,
+            iris_realtime_store: Arc::new(RealtimeStore::new()),
             cache: RedisCache::new(redis_client),
         }))
         .fallback(|| async { "Nothing here :/" });
@@ -187,10 +227,62 @@ async fn main() {
     server.await.unwrap();
 }
 
+/// Reads which RIS backend to use for which endpoint from the environment.
+///
+/// Every endpoint can be configured with `RIS_<ENDPOINT>_BACKEND` (e.G. `RIS_JOURNEY_DETAILS_BACKEND=db-api-marketplace`), \
+/// endpoints that are not configured use Zugportal if it supports them and the DB API Marketplace otherwise. \
+/// Panics if an endpoint is configured with a backend that does not support it.
+fn ris_backends() -> RisBackends {
+    let zugportal = RisBackend::zugportal(std::env::var("ZUGPORTAL_URL").ok());
+
+    let ris_api_key = std::env::var("RIS_API_KEY").unwrap_or_else(|_| {
+        tracing::warn!(
+            "RIS_API_KEY env variable is not set. The DB API Marketplace backend will not work"
+        );
+        String::new()
+    });
+    let ris_client_id = std::env::var("RIS_CLIENT_ID").unwrap_or_else(|_| {
+        tracing::warn!(
+            "RIS_CLIENT_ID env variable is not set. The DB API Marketplace backend will not work"
+        );
+        String::new()
+    });
+    let db_api_marketplace =
+        RisBackend::db_api_marketplace(std::env::var("RIS_URL").ok(), &ris_client_id, &ris_api_key);
+
+    let backend = |endpoint: &str, default: RisBackend| {
+        let variable = format!("RIS_{endpoint}_BACKEND");
+
+        match std::env::var(&variable).map(|kind| kind.parse::<RisBackendKind>()) {
+            Ok(Ok(RisBackendKind::Zugportal)) => zugportal.clone(),
+            Ok(Ok(RisBackendKind::DbApiMarketplace)) => db_api_marketplace.clone(),
+            Ok(Err(err)) => panic!("Invalid {variable}: {err}"),
+            Err(_) => default,
+        }
+    };
+
+    let defaults = RisBackends::new(zugportal.clone(), db_api_marketplace.clone());
+
+    let backends = RisBackends {
+        station_board: backend("STATION_BOARD", defaults.station_board),
+        journey_details: backend("JOURNEY_DETAILS", defaults.journey_details),
+        journey_search: backend("JOURNEY_SEARCH", defaults.journey_search),
+        station_information: backend("STATION_INFORMATION", defaults.station_information),
+        station_search: backend("STATION_SEARCH", defaults.station_search),
+    };
+
+    if let Err(err) = backends.validate() {
+        panic!("Invalid RIS backend configuration: {err}");
+    }
+
+    backends
+}
+
 pub struct SharedState {
     vendo_client: Arc<VendoClient>,
     ris_client: Arc<RisClient>,
     iris_client: Arc<IrisClient>,
+    iris_realtime_store: Arc<RealtimeStore>,
     cache: RedisCache,
 }
 
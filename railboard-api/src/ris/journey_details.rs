@@ -17,17 +17,15 @@ use crate::{
     get,
     path = "/ris/v1/journey_details/{id}",
     params(
-("id" = String, Path, description = "The id of this journey (can be optained e.G. through the journey search endpoint)")
+("id" = String, Path, description = "The id of this journey (can be optained e.G. through the station board endpoint)")
     ),
     tag = "Ris",
     responses(
 (status = 200, description = "The requested Journey Details", body = RisJourneyDetails),
-(status = 400, description = "The Error returned by Ris, will be the Ris Domain with UnderlyingApiError Variant 3 or 4", body = RailboardApiError),
+(status = 400, description = "The Error returned by the Zugportal API (Ris), will be the Ris Domain with UnderlyingApiError Variant 5", body = RailboardApiError),
 (status = 500, description = "The Error returned if the request or deserialization fails, will be domain Request", body = RailboardApiError)
     ),
 )]
-#[allow(deprecated)]
-#[deprecated(note = "the endpoint is not being maintained anymore, see ris-client")]
 pub async fn journey_details(
     Path(id): Path<String>,
     state: State<Arc<SharedState>>,
@@ -8,19 +8,23 @@ use chrono::{DateTime, FixedOffset, TimeZone};
 use chrono_tz::Europe::Berlin;
 use serde::Deserialize;
 
-use ris_client::station_board::RisStationBoard;
+use ris_client::station_board::{ExpandTimeFrame, RisStationBoard};
 
 use crate::{
     cache::{CachableObject, Cache},
-    error::RailboardResult,
+    error::{ErrorDomain, RailboardApiError, RailboardResult},
     SharedState,
 };
 
+/// The longest time range a station board can be requested for, since it is requested from Zugportal in chunks of an hour.
+const MAX_TIME_RANGE_HOURS: i64 = 12;
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StationBoardQuery {
     pub time_start: Option<DateTime<FixedOffset>>,
     pub time_end: Option<DateTime<FixedOffset>>,
+    pub expand_time_frame: Option<ExpandTimeFrame>,
 }
 
 #[utoipa::path(
@@ -29,12 +33,13 @@ path = "/ris/v1/station_board/{eva}",
 params(
 ("eva" = String, Path, description = "The Eva Number of the Station you are requesting"),
 ("timeStart" = Option < String >, Query, description = "The Start Time of the Time Range you are requesting"),
-("timeEnd" = Option < String >, Query, description = "The End Time of the Time Range you are requesting")
+("timeEnd" = Option < String >, Query, description = "The End Time of the Time Range you are requesting, at most 12 hours after the Start Time"),
+("expandTimeFrame" = Option < ExpandTimeFrame >, Query, description = "How the Time Range is expanded to e.G. include delayed trains. Defaults to TIME_START")
 ),
 tag = "Ris",
 responses(
 (status = 200, description = "The requested Station Board", body = RisStationBoard),
-(status = 400, description = "The Error returned by the Zugportal API (Ris), will be the Ris Domain with UnderlyingApiError Variant 5", body = RailboardApiError),
+(status = 400, description = "The Error returned by the Zugportal API (Ris), will be the Ris Domain with UnderlyingApiError Variant 5, or the Input Domain if the Time Range is longer than 12 hours", body = RailboardApiError),
 (status = 500, description = "The Error returned if the request or deserialization fails, will be domain Request", body = RailboardApiError)
 )
 )]
@@ -51,14 +56,25 @@ pub async fn station_board(
         .time_end
         .map(|time_end| Berlin.from_utc_datetime(&time_end.naive_utc()));
 
+    let expand_time_frame = query.expand_time_frame.unwrap_or_default();
+
     if let (Some(time_start), Some(time_end)) = (time_start, time_end) {
+        if time_end - time_start > chrono::Duration::hours(MAX_TIME_RANGE_HOURS) {
+            return Err(RailboardApiError {
+                domain: ErrorDomain::Input,
+                message: format!("The time range can be at most {MAX_TIME_RANGE_HOURS} hours long"),
+                error: None,
+            });
+        }
+
         if let Some(cached) = state
             .cache
             .get_from_id(&format!(
-                "ris.station-board.{}.{}.{}",
+                "ris.station-board.{}.{}.{}.{}",
                 eva,
                 time_start.naive_utc().format("%Y-%m-%dT%H:%M"),
-                time_end.naive_utc().format("%Y-%m-%dT%H:%M")
+                time_end.naive_utc().format("%Y-%m-%dT%H:%M"),
+                expand_time_frame_key(expand_time_frame)
             ))
             .await
         {
@@ -68,15 +84,25 @@ pub async fn station_board(
 
     let station_board = state
         .ris_client
-        .station_board(&eva, time_start, time_end)
+        .station_board(&eva, time_start, time_end, expand_time_frame)
         .await?;
 
     {
         let station_board = station_board.clone();
         tokio::spawn(async move {
-            let _ = station_board.insert_to_cache(&state.cache, None).await;
+            let _ = station_board
+                .insert_to_cache(&state.cache, Some(expand_time_frame_key(expand_time_frame)))
+                .await;
         });
     }
 
     Ok(Json(station_board))
 }
+
+fn expand_time_frame_key(expand_time_frame: ExpandTimeFrame) -> &'static str {
+    match expand_time_frame {
+        ExpandTimeFrame::TimeStart => "time-start",
+        ExpandTimeFrame::TimeEnd => "time-end",
+        ExpandTimeFrame::None => "none",
+    }
+}
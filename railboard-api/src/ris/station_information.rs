@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
+use serde::Deserialize;
 
 use ris_client::station_information::RisStationInformation;
 
@@ -13,11 +14,17 @@ use crate::{
     SharedState,
 };
 
+#[derive(Deserialize)]
+pub struct RisStationInformationQuery {
+    pub language: Option<String>,
+}
+
 #[utoipa::path(
 get,
 path = "/ris/v1/station/{eva}",
 params(
 ("eva" = String, Path, description = "The Eva Number of the Station you are requesting"),
+("language" = Option < String >, Query, description = "The preferred language of the names (for example: \"en\"), falls back to German if Ris does not provide it (default: de)"),
 ),
 tag = "Ris",
 responses(
@@ -30,6 +37,7 @@ responses(
 #[deprecated(note = "the endpoint is not being maintained anymore, see ris-client")]
 pub async fn station_information(
     Path(eva): Path<String>,
+    Query(query): Query<RisStationInformationQuery>,
     State(state): State<Arc<SharedState>>,
 ) -> RailboardResult<Json<RisStationInformation>> {
     if let Some(cached) = state
@@ -37,7 +45,7 @@ pub async fn station_information(
         .get_from_id(&format!("ris.station-information.{}", &eva))
         .await
     {
-        return Ok(Json(cached));
+        return Ok(Json(localize(cached, query.language.as_deref())));
     }
 
     let response = state.ris_client.station_information(&eva).await?;
@@ -59,5 +67,13 @@ pub async fn station_information(
         });
     }
 
-    Ok(Json(response))
+    Ok(Json(localize(response, query.language.as_deref())))
+}
+
+fn localize(mut station: RisStationInformation, language: Option<&str>) -> RisStationInformation {
+    if let Some(language) = language {
+        station.localize(language);
+    }
+
+    station
 }
@@ -17,6 +17,7 @@ use crate::{
 #[derive(Deserialize)]
 pub struct RisStationSearchQuery {
     pub limit: Option<u32>,
+    pub language: Option<String>,
 }
 
 #[utoipa::path(
@@ -24,7 +25,8 @@ pub struct RisStationSearchQuery {
     path = "/ris/v1/station_search/{query}",
     params(
         ("query" = String, Path, description = "The Query for the station (for example: \"Leipzig\")"),
-        ("limit" = Option<u32>, Query, description = "The maximum amount of results to return (default: 25)")
+        ("limit" = Option<u32>, Query, description = "The maximum amount of results to return (default: 25)"),
+        ("language" = Option<String>, Query, description = "The preferred language of the name (for example: \"en\"), falls back to German if Ris does not provide it (default: de)")
     ),
     tag = "Ris",
     responses(
@@ -40,16 +42,21 @@ pub async fn station_search_by_name(
     Query(query_params): Query<RisStationSearchQuery>,
     state: State<Arc<SharedState>>,
 ) -> RailboardResult<Json<Vec<RisStationSearchElement>>> {
+    let limit = query_params.limit;
+    let language = query_params.language;
+
     if let Some(cached) = state
         .cache
-        .get_from_id(&format!("ris.journey-details.{}", &query))
+        .get_from_id(&format!(
+            "ris.station-search-by-name.{}.{}",
+            &query,
+            limit.unwrap_or(25)
+        ))
         .await
     {
-        return Ok(Json(cached));
+        return Ok(Json(localize(cached, language.as_deref())));
     }
 
-    let limit = query_params.limit;
-
     let response = state
         .ris_client
         .station_search_by_name(&query, limit)
@@ -67,5 +74,18 @@ pub async fn station_search_by_name(
         });
     }
 
-    Ok(Json(response))
+    Ok(Json(localize(response, language.as_deref())))
+}
+
+fn localize(
+    mut stations: Vec<RisStationSearchElement>,
+    language: Option<&str>,
+) -> Vec<RisStationSearchElement> {
+    if let Some(language) = language {
+        for station in &mut stations {
+            station.localize(language);
+        }
+    }
+
+    stations
 }
@@ -2,10 +2,14 @@ use std::sync::Arc;
 
 use axum::{routing::get, Router};
 
+use vendo_client::station_board::VendoTransportType;
+
 use crate::SharedState;
 
+pub mod connections;
 pub mod journey_details;
 pub mod location_search;
+pub mod nearby;
 pub mod station_board;
 
 pub fn router() -> Router<Arc<SharedState>> {
@@ -19,4 +23,17 @@ pub fn router() -> Router<Arc<SharedState>> {
             "/location_search/:query",
             get(location_search::location_search),
         )
+        .route("/connections", get(connections::connections))
+        .route("/nearby", get(nearby::nearby))
+}
+
+/// Parses the comma separated `products` query parameter, an empty list (e.g. `?products=`) means all transport types.
+pub(crate) fn parse_products(products: &str) -> Result<Option<Vec<VendoTransportType>>, String> {
+    let transport_types = products
+        .split(',')
+        .filter(|product| !product.trim().is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(transport_types).filter(|transport_types| !transport_types.is_empty()))
 }
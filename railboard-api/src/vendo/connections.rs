@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::TimeZone;
+use chrono_tz::Europe::Berlin;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use vendo_client::connection_search::{VendoConnectionSearch, VendoConnectionTime};
+use vendo_client::shared::PagingDirection;
+
+use super::parse_products;
+use crate::{
+    cache::{transport_types_key, CachableObject, Cache},
+    error::{ErrorDomain, RailboardApiError, RailboardResult},
+    SharedState,
+};
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionsQuery {
+    /// The eva number or location id of the location the connections start at
+    pub from: String,
+    /// The eva number or location id of the location the connections end at
+    pub to: String,
+    /// Comma separated eva numbers or location ids the connections have to pass through
+    pub via: Option<String>,
+    /// The date (Unix Timestamp) the connections should depart at. If not provided, the current date is used.
+    pub time: Option<i64>,
+    /// Whether `time` is the time the connections should arrive at instead (default: false)
+    pub arrival: Option<bool>,
+    /// The maximum amount of transfers, if not provided Vendo decides
+    pub max_transfers: Option<u32>,
+    /// The cursor of previously requested connections with the same parameters, `time` and `arrival` are ignored if it is set
+    pub cursor: Option<String>,
+    /// Whether to get the connections before or after the ones of the cursor (default: later)
+    pub direction: Option<PagingDirection>,
+    /// Comma separated transport types the connections may use (for example: "ICE,REGIONAL,SBAHN"), all transport types if not provided.
+    /// One of ICE, IC, IR, REGIONAL, SBAHN, BUS, SHIP, UBAHN, TRAM or TAXI.
+    pub products: Option<String>,
+}
+
+#[utoipa::path(
+get,
+path = "/vendo/v1/connections",
+params(ConnectionsQuery),
+tag = "Vendo",
+responses(
+(status = 200, description = "The connections found by Vendo", body = VendoConnectionSearch),
+(status = 400, description = "The Error returned by Vendo", body = RailboardApiError),
+(status = 500, description = "The Error returned if the request or deserialization fails", body = RailboardApiError)
+)
+)]
+pub async fn connections(
+    Query(params): Query<ConnectionsQuery>,
+    State(state): State<Arc<SharedState>>,
+) -> RailboardResult<Json<VendoConnectionSearch>> {
+    let date = if let Some(time) = params.time {
+        Berlin.from_utc_datetime(&chrono::NaiveDateTime::from_timestamp_opt(time, 0).ok_or(
+            RailboardApiError {
+                domain: ErrorDomain::Input,
+                message: "Invalid time".to_string(),
+                error: None,
+            },
+        )?)
+    } else {
+        Berlin.from_utc_datetime(&chrono::Utc::now().naive_utc())
+    };
+
+    let arrival = params.arrival.unwrap_or(false);
+
+    let transport_types = params
+        .products
+        .as_deref()
+        .map(parse_products)
+        .transpose()
+        .map_err(|message| RailboardApiError {
+            domain: ErrorDomain::Input,
+            message,
+            error: None,
+        })?
+        .flatten();
+
+    let via: Vec<String> = params
+        .via
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|via| !via.is_empty())
+        .map(String::from)
+        .collect();
+
+    let direction = params.direction.unwrap_or_default();
+
+    let time_key = match &params.cursor {
+        Some(cursor) => format!(
+            "{}.{}",
+            match direction {
+                PagingDirection::Earlier => "earlier",
+                PagingDirection::Later => "later",
+            },
+            cursor
+        ),
+        None => format!(
+            "{}.{}",
+            date.format("%Y-%m-%dT%H:%M"),
+            if arrival { "arrival" } else { "departure" }
+        ),
+    };
+
+    let information = format!(
+        "{}.{}.{}.{}.{}.{}",
+        params.from,
+        params.to,
+        via.join(","),
+        transport_types_key(transport_types.as_deref()),
+        params
+            .max_transfers
+            .map(|max_transfers| max_transfers.to_string())
+            .unwrap_or_default(),
+        time_key
+    );
+
+    if let Some(cached) = state
+        .cache
+        .get_from_id(&format!("vendo.connections.{information}"))
+        .await
+    {
+        return Ok(Json(cached));
+    }
+
+    let time = if let Some(cursor) = params.cursor {
+        VendoConnectionTime::Cursor { cursor, direction }
+    } else if arrival {
+        VendoConnectionTime::Arrival(date)
+    } else {
+        VendoConnectionTime::Departure(date)
+    };
+
+    let connections = state
+        .vendo_client
+        .connection_search(
+            &params.from,
+            &params.to,
+            Some(via),
+            Some(time),
+            transport_types,
+            params.max_transfers,
+        )
+        .await?;
+
+    {
+        let connections = connections.clone();
+        tokio::spawn(async move {
+            connections
+                .insert_to_cache(&state.cache, Some(&information))
+                .await
+        });
+    }
+
+    Ok(Json(connections))
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use vendo_client::location_search::{VendoLocationSearchCoordinates, VendoLocationType};
+use vendo_client::nearby::{nearby_locations, VendoNearbyLocation};
+
+use crate::{
+    cache::{CachableObject, Cache},
+    error::{ErrorDomain, RailboardApiError, RailboardResult},
+    SharedState,
+};
+
+/// Coordinates are rounded to a grid of about 100 meters for caching
+const GRID_PRECISION: f64 = 1000.0;
+/// Covers the distance between the requested coordinates and the nearest grid point
+const GRID_SLACK: u32 = 100;
+/// Locations requested per grid point, the requested maximum is applied afterwards
+const GRID_RESULTS: u32 = 50;
+const MAX_RADIUS: u32 = 10_000;
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct NearbyQuery {
+    /// The latitude of the coordinates to search around
+    pub lat: f64,
+    /// The longitude of the coordinates to search around
+    pub lon: f64,
+    /// The radius to search in meters (default: 1000, at most 10000)
+    pub radius: Option<u32>,
+    /// The maximum amount of locations to return (default: 10, at most 50)
+    pub max_results: Option<u32>,
+    /// Only return stations (default: true), otherwise addresses and points of interest are returned as well
+    pub stations_only: Option<bool>,
+}
+
+#[utoipa::path(
+get,
+path = "/vendo/v1/nearby",
+params(NearbyQuery),
+tag = "Vendo",
+responses(
+(status = 200, description = "The locations around the coordinates sorted by distance", body = [VendoNearbyLocation]),
+(status = 400, description = "The Error returned by Vendo or the Input Domain if the coordinates are invalid", body = RailboardApiError),
+(status = 500, description = "The Error returned if the request or deserialization fails", body = RailboardApiError)
+)
+)]
+pub async fn nearby(
+    Query(params): Query<NearbyQuery>,
+    State(state): State<Arc<SharedState>>,
+) -> RailboardResult<Json<Vec<VendoNearbyLocation>>> {
+    if !(-90.0..=90.0).contains(&params.lat) || !(-180.0..=180.0).contains(&params.lon) {
+        return Err(RailboardApiError {
+            domain: ErrorDomain::Input,
+            message: "Invalid coordinates".to_string(),
+            error: None,
+        });
+    }
+
+    let radius = params.radius.unwrap_or(1000).min(MAX_RADIUS);
+    let max_results = params.max_results.unwrap_or(10).min(GRID_RESULTS);
+    let stations_only = params.stations_only.unwrap_or(true);
+
+    let grid_latitude = (params.lat * GRID_PRECISION).round() / GRID_PRECISION;
+    let grid_longitude = (params.lon * GRID_PRECISION).round() / GRID_PRECISION;
+
+    let information = format!(
+        "{:.3}.{:.3}.{}.{}",
+        grid_latitude,
+        grid_longitude,
+        radius,
+        if stations_only { "stations" } else { "all" }
+    );
+
+    let origin = VendoLocationSearchCoordinates {
+        latitude: params.lat,
+        longitude: params.lon,
+    };
+
+    if let Some(cached) = state
+        .cache
+        .get_from_id::<Vec<VendoNearbyLocation>>(&format!("vendo.nearby.{information}"))
+        .await
+    {
+        return Ok(Json(locations_around(&origin, cached, radius, max_results)));
+    }
+
+    let locations = state
+        .vendo_client
+        .nearby(
+            grid_latitude,
+            grid_longitude,
+            Some(radius + GRID_SLACK),
+            Some(GRID_RESULTS),
+            stations_only.then(|| vec![VendoLocationType::Station]),
+        )
+        .await?;
+
+    {
+        let locations = locations.clone();
+        tokio::spawn(async move {
+            locations
+                .insert_to_cache(&state.cache, Some(&information))
+                .await
+        });
+    }
+
+    Ok(Json(locations_around(
+        &origin,
+        locations,
+        radius,
+        max_results,
+    )))
+}
+
+/// Measures the locations found around the grid point from the requested coordinates instead.
+fn locations_around(
+    origin: &VendoLocationSearchCoordinates,
+    locations: Vec<VendoNearbyLocation>,
+    radius: u32,
+    max_results: u32,
+) -> Vec<VendoNearbyLocation> {
+    nearby_locations(
+        origin,
+        locations.into_iter().map(|location| location.location),
+        radius,
+        max_results as usize,
+    )
+}
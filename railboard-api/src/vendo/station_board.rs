@@ -9,10 +9,13 @@ use chrono_tz::Europe::Berlin;
 use serde::Deserialize;
 use utoipa::IntoParams;
 
-use vendo_client::station_board::VendoStationBoard;
+use vendo_client::shared::PagingDirection;
+use vendo_client::station_board::{VendoStationBoard, VendoTransportType};
+
+use super::parse_products;
 
 use crate::{
-    cache::{CachableObject, Cache},
+    cache::{transport_types_key, CachableObject, Cache},
     error::{ErrorDomain, RailboardApiError, RailboardResult},
     SharedState,
 };
@@ -21,6 +24,13 @@ use crate::{
 pub struct StationBoardQuery {
     /// The date (Unix Timestamp) to request the station board for. If not provided, the current date is used.
     pub date: Option<i64>,
+    /// The cursor of a previously requested page of the station board, `date` is ignored if it is set
+    pub cursor: Option<String>,
+    /// Whether to get the page before or after the one of the cursor (default: later)
+    pub direction: Option<PagingDirection>,
+    /// Comma separated transport types to keep (for example: "ICE,REGIONAL,SBAHN"), all transport types if not provided.
+    /// One of ICE, IC, IR, REGIONAL, SBAHN, BUS, SHIP, UBAHN, TRAM or TAXI.
+    pub products: Option<String>,
 }
 
 #[utoipa::path(
@@ -42,6 +52,29 @@ pub async fn station_board(
     Query(params): Query<StationBoardQuery>,
     State(state): State<Arc<SharedState>>,
 ) -> RailboardResult<Json<VendoStationBoard>> {
+    let transport_types = params
+        .products
+        .as_deref()
+        .map(parse_products)
+        .transpose()
+        .map_err(|message| RailboardApiError {
+            domain: ErrorDomain::Input,
+            message,
+            error: None,
+        })?
+        .flatten();
+
+    if let Some(cursor) = params.cursor {
+        return station_board_page(
+            &id,
+            cursor,
+            params.direction.unwrap_or_default(),
+            transport_types,
+            state,
+        )
+        .await;
+    }
+
     let date = if let Some(date) = params.date {
         Berlin.from_utc_datetime(&chrono::NaiveDateTime::from_timestamp_opt(date, 0).ok_or(
             RailboardApiError {
@@ -57,8 +90,9 @@ pub async fn station_board(
     if let Some(cached) = state
         .cache
         .get_from_id(&format!(
-            "vendo.station-board.{}.{}.{}",
+            "vendo.station-board.{}.{}.{}.{}",
             id,
+            transport_types_key(transport_types.as_deref()),
             date.format("%Y-%m-%d"),
             date.format("%H:%M")
         ))
@@ -67,7 +101,10 @@ pub async fn station_board(
         return Ok(Json(cached));
     }
 
-    let station_board = state.vendo_client.station_board(&id, date).await?;
+    let station_board = state
+        .vendo_client
+        .station_board(&id, date, transport_types)
+        .await?;
 
     {
         let station_board = station_board.clone();
@@ -76,3 +113,49 @@ pub async fn station_board(
 
     Ok(Json(station_board))
 }
+
+async fn station_board_page(
+    id: &str,
+    cursor: String,
+    direction: PagingDirection,
+    transport_types: Option<Vec<VendoTransportType>>,
+    state: Arc<SharedState>,
+) -> RailboardResult<Json<VendoStationBoard>> {
+    let information = format!(
+        "{}.{}",
+        match direction {
+            PagingDirection::Earlier => "earlier",
+            PagingDirection::Later => "later",
+        },
+        cursor
+    );
+
+    if let Some(cached) = state
+        .cache
+        .get_from_id(&format!(
+            "vendo.station-board.{}.{}.{}",
+            id,
+            transport_types_key(transport_types.as_deref()),
+            information
+        ))
+        .await
+    {
+        return Ok(Json(cached));
+    }
+
+    let station_board = state
+        .vendo_client
+        .station_board_page(id, &cursor, direction, transport_types)
+        .await?;
+
+    {
+        let station_board = station_board.clone();
+        tokio::spawn(async move {
+            station_board
+                .insert_to_cache(&state.cache, Some(&information))
+                .await
+        });
+    }
+
+    Ok(Json(station_board))
+}
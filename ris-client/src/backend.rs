@@ -0,0 +1,217 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::UnsupportedBackendError;
+
+const ZUGPORTAL_URL: &str = "https://zugportal.de/@prd/zupo-travel-information/api/public";
+const DB_API_MARKETPLACE_URL: &str = "https://apis.deutschebahn.com";
+
+/// An upstream that serves RIS data, each with its own base url and authentication.
+///
+/// The credentials are redacted when the backend is formatted with `{:?}`.
+#[derive(Clone, PartialEq, Eq)]
+pub enum RisBackend {
+    /// The api the Zugportal app uses, which needs no credentials
+    Zugportal { base_url: String },
+    /// The official RIS apis of the DB API Marketplace
+    DbApiMarketplace {
+        base_url: String,
+        client_id: String,
+        api_key: String,
+    },
+}
+
+impl RisBackend {
+    /// The Zugportal backend, `base_url` defaults to the public Zugportal api.
+    pub fn zugportal(base_url: Option<String>) -> Self {
+        Self::Zugportal {
+            base_url: base_url.unwrap_or_else(|| String::from(ZUGPORTAL_URL)),
+        }
+    }
+
+    /// The DB API Marketplace backend, `base_url` defaults to `https://apis.deutschebahn.com`.
+    pub fn db_api_marketplace(base_url: Option<String>, client_id: &str, api_key: &str) -> Self {
+        Self::DbApiMarketplace {
+            base_url: base_url.unwrap_or_else(|| String::from(DB_API_MARKETPLACE_URL)),
+            client_id: client_id.to_string(),
+            api_key: api_key.to_string(),
+        }
+    }
+
+    pub fn kind(&self) -> RisBackendKind {
+        match self {
+            RisBackend::Zugportal { .. } => RisBackendKind::Zugportal,
+            RisBackend::DbApiMarketplace { .. } => RisBackendKind::DbApiMarketplace,
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        match self {
+            RisBackend::Zugportal { base_url } => base_url,
+            RisBackend::DbApiMarketplace { base_url, .. } => base_url,
+        }
+    }
+
+    /// Fails with an [`UnsupportedBackendError`] if the `endpoint` is not supported by the backend.
+    pub(crate) fn require(&self, endpoint: RisEndpoint) -> Result<&Self, UnsupportedBackendError> {
+        if endpoint.supports(self.kind()) {
+            Ok(self)
+        } else {
+            Err(UnsupportedBackendError {
+                endpoint: endpoint.name(),
+                backend: self.kind(),
+            })
+        }
+    }
+
+    /// Builds a GET request for `path` (relative to the base url) including the authentication of the backend.
+    pub(crate) fn get(&self, client: &Client, path: &str) -> RequestBuilder {
+        let request = client.get(format!("{}{}", self.base_url(), path));
+
+        match self {
+            RisBackend::Zugportal { .. } => request,
+            RisBackend::DbApiMarketplace {
+                client_id, api_key, ..
+            } => request
+                .header("db-api-key", api_key)
+                .header("db-client-id", client_id),
+        }
+    }
+}
+
+impl Debug for RisBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RisBackend::Zugportal { base_url } => f
+                .debug_struct("Zugportal")
+                .field("base_url", base_url)
+                .finish(),
+            RisBackend::DbApiMarketplace { base_url, .. } => f
+                .debug_struct("DbApiMarketplace")
+                .field("base_url", base_url)
+                .field("client_id", &"<redacted>")
+                .field("api_key", &"<redacted>")
+                .finish(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RisBackendKind {
+    Zugportal,
+    DbApiMarketplace,
+}
+
+impl Display for RisBackendKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RisBackendKind::Zugportal => write!(f, "zugportal"),
+            RisBackendKind::DbApiMarketplace => write!(f, "db-api-marketplace"),
+        }
+    }
+}
+
+impl FromStr for RisBackendKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "zugportal" => Ok(RisBackendKind::Zugportal),
+            "db-api-marketplace" | "db-api" | "marketplace" => Ok(RisBackendKind::DbApiMarketplace),
+            _ => Err(format!("Unknown RIS backend: {value}")),
+        }
+    }
+}
+
+/// The endpoints of the [`RisClient`](crate::RisClient) that can be served by different backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RisEndpoint {
+    StationBoard,
+    JourneyDetails,
+    JourneySearch,
+    StationInformation,
+    StationSearch,
+}
+
+impl RisEndpoint {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RisEndpoint::StationBoard => "station board",
+            RisEndpoint::JourneyDetails => "journey details",
+            RisEndpoint::JourneySearch => "journey search",
+            RisEndpoint::StationInformation => "station information",
+            RisEndpoint::StationSearch => "station search",
+        }
+    }
+
+    /// Whether a backend of `kind` can serve this endpoint.
+    pub fn supports(&self, kind: RisBackendKind) -> bool {
+        match self {
+            RisEndpoint::StationBoard => kind == RisBackendKind::Zugportal,
+            RisEndpoint::JourneyDetails => true,
+            RisEndpoint::JourneySearch
+            | RisEndpoint::StationInformation
+            | RisEndpoint::StationSearch => kind == RisBackendKind::DbApiMarketplace,
+        }
+    }
+}
+
+/// The backend every endpoint of the [`RisClient`](crate::RisClient) uses.
+///
+/// Not every backend supports every endpoint, requesting an endpoint from a backend that does not support it \
+/// fails with [`RisOrRequestError::UnsupportedBackend`](crate::RisOrRequestError::UnsupportedBackend).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RisBackends {
+    /// Only supported by Zugportal
+    pub station_board: RisBackend,
+    /// Supported by Zugportal and the DB API Marketplace
+    pub journey_details: RisBackend,
+    /// Only supported by the DB API Marketplace
+    pub journey_search: RisBackend,
+    /// Only supported by the DB API Marketplace
+    pub station_information: RisBackend,
+    /// Only supported by the DB API Marketplace
+    pub station_search: RisBackend,
+}
+
+impl RisBackends {
+    /// Uses Zugportal for every endpoint it supports and the DB API Marketplace for the rest.
+    pub fn new(zugportal: RisBackend, db_api_marketplace: RisBackend) -> Self {
+        Self {
+            station_board: zugportal.clone(),
+            journey_details: zugportal,
+            journey_search: db_api_marketplace.clone(),
+            station_information: db_api_marketplace.clone(),
+            station_search: db_api_marketplace,
+        }
+    }
+
+    /// The backend configured for `endpoint`.
+    pub fn get(&self, endpoint: RisEndpoint) -> &RisBackend {
+        match endpoint {
+            RisEndpoint::StationBoard => &self.station_board,
+            RisEndpoint::JourneyDetails => &self.journey_details,
+            RisEndpoint::JourneySearch => &self.journey_search,
+            RisEndpoint::StationInformation => &self.station_information,
+            RisEndpoint::StationSearch => &self.station_search,
+        }
+    }
+
+    /// Fails with an [`UnsupportedBackendError`] for the first endpoint whose backend does not support it, \
+    /// so a misconfiguration can be caught before any request is made.
+    pub fn validate(&self) -> Result<(), UnsupportedBackendError> {
+        [
+            RisEndpoint::StationBoard,
+            RisEndpoint::JourneyDetails,
+            RisEndpoint::JourneySearch,
+            RisEndpoint::StationInformation,
+            RisEndpoint::StationSearch,
+        ]
+        .into_iter()
+        .try_for_each(|endpoint| self.get(endpoint).require(endpoint).map(|_| ()))
+    }
+}
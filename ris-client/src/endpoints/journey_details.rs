@@ -5,28 +5,73 @@ use serde::Deserialize;
 pub use transformed::*;
 
 use crate::journey_details::response::{EventType, JourneyDetailsEvent, JourneyDetailsResponse};
-use crate::{RisClient, RisError, RisOrRequestError, RisUnauthorizedError};
+use crate::journey_details::zugportal_response::{
+    ZugportalJourneyEvent, ZugportalJourneyResponse, ZugportalJourneyTrain,
+};
+use crate::operators::find_operator;
+use crate::{
+    RisBackend, RisBackendKind, RisClient, RisError, RisOrRequestError, RisUnauthorizedError,
+    ZugportalError,
+};
 
 mod response;
 mod transformed;
+pub mod zugportal_response;
 
 impl RisClient {
-    #[deprecated(
-        note = "the only known api key was revoked, so i cannot maintain this endpoint anymore"
-    )]
+    /// Get the details of a journey from the backend configured for journey details.
+    ///
+    /// Takes the journey id as it is returned by the station board, e.G. in [`RisStationBoardItem::journey_id`](crate::station_board::RisStationBoardItem::journey_id). \
+    /// Zugportal and the DB API Marketplace use different journey ids, so the id has to come from the same backend.
     pub async fn journey_details(&self, id: &str) -> Result<RisJourneyDetails, RisOrRequestError> {
+        let backend = &self.backends.journey_details;
+
+        match backend.kind() {
+            RisBackendKind::Zugportal => self.zugportal_journey_details(backend, id).await,
+            RisBackendKind::DbApiMarketplace => self.db_api_journey_details(backend, id).await,
+        }
+    }
+
+    async fn zugportal_journey_details(
+        &self,
+        backend: &RisBackend,
+        id: &str,
+    ) -> Result<RisJourneyDetails, RisOrRequestError> {
         let _permit = self.semaphore.acquire().await;
 
-        let url = format!(
-            "{}/db/apis/ris-journeys/v1/eventbased/{}",
-            self.base_url, id,
-        );
+        let response: ZugportalJourneyOrErrorResponse = backend
+            .get(
+                &self.client,
+                &format!("/ri/journey/{}", urlencoding::encode(id)),
+            )
+            .send()
+            .await?
+            .json()
+            .await?;
 
-        let response: JourneyDetailsResponse = self
-            .client
-            .get(&url)
-            .header("db-api-key", self.db_api_key.clone())
-            .header("db-client-id", self.db_client_id.clone())
+        match response {
+            ZugportalJourneyOrErrorResponse::Response(response) => {
+                from_zugportal_journey(*response).ok_or(RisOrRequestError::NotFoundError)
+            }
+            ZugportalJourneyOrErrorResponse::Error(error) => {
+                Err(RisOrRequestError::ZugportalError(error))
+            }
+        }
+    }
+
+    // the only known api key for the DB API Marketplace was revoked, so this is not maintained anymore
+    async fn db_api_journey_details(
+        &self,
+        backend: &RisBackend,
+        id: &str,
+    ) -> Result<RisJourneyDetails, RisOrRequestError> {
+        let _permit = self.semaphore.acquire().await;
+
+        let response: JourneyDetailsResponse = backend
+            .get(
+                &self.client,
+                &format!("/db/apis/ris-journeys/v1/eventbased/{id}"),
+            )
             .send()
             .await?
             .json()
@@ -99,14 +144,12 @@ impl RisClient {
                     }
                 }
 
-                let custom_operator_name =
-                    match departure_arrival.administration.administration_id.as_str() {
-                        "80" => "DB Fernverkehr AG",
-                        "82" => "CFL",
-                        "87" => "SNCF",
-                        "88" => "SNCB",
-                        _ => &departure_arrival.administration.operator_name,
-                    };
+                let operator = find_operator(
+                    &departure_arrival.administration.administration_id,
+                    &departure_arrival.administration.operator_code,
+                    &departure_arrival.administration.operator_name,
+                )
+                .cloned();
 
                 RisJourneyStop {
                     stop_id: departure_arrival.station.eva_number,
@@ -144,9 +187,15 @@ impl RisClient {
                     real_platform: departure_arrival.platform,
                     administration: RisJourneyStopAdministration {
                         id: departure_arrival.administration.administration_id,
-                        name: custom_operator_name.to_string(),
+                        name: operator
+                            .as_ref()
+                            .map(|operator| operator.display_name.clone())
+                            .unwrap_or_else(|| {
+                                departure_arrival.administration.operator_name.clone()
+                            }),
                         operator_code: departure_arrival.administration.operator_code,
                         ris_name: departure_arrival.administration.operator_name,
+                        operator,
                     },
                 }
             })
@@ -177,6 +226,123 @@ impl RisClient {
     }
 }
 
+/// Transforms a journey returned by Zugportal into [`RisJourneyDetails`].
+///
+/// Returns `None` if the journey has no stops.
+pub fn from_zugportal_journey(response: ZugportalJourneyResponse) -> Option<RisJourneyDetails> {
+    let (Some(origin), Some(destination)) = (response.stops.first(), response.stops.last()) else {
+        return None;
+    };
+
+    let origin_id = origin.station.eva_no.to_owned();
+    let origin_name = origin.station.name.to_owned();
+    let destination_id = destination.station.eva_no.to_owned();
+    let destination_name = destination.station.name.to_owned();
+
+    let cancelled = response.canceled
+        || response
+            .stops
+            .iter()
+            .all(|stop| stop.canceled || stop.station.canceled);
+
+    let journey_type = response.train.r#type.to_owned();
+    let mut train = response.train;
+
+    let stops = response
+        .stops
+        .into_iter()
+        .map(|stop| {
+            // the train keeps its category and number until the next stop that changes them
+            if let Some(changed_train) = stop.train {
+                train = changed_train;
+            }
+
+            let cancelled = stop.canceled || stop.station.canceled;
+
+            let event = |event: ZugportalJourneyEvent| RisJourneyStopEvent {
+                cancelled: cancelled || event.canceled,
+                additional: stop.additional || event.additional,
+                on_demand: stop.on_demand,
+                scheduled: event.time,
+                realtime: event.time_predicted,
+                time_type: event.time_type,
+            };
+
+            RisJourneyStop {
+                stop_id: stop.station.eva_no,
+                stop_name: stop.station.name,
+                arrival: stop.arrival.map(event),
+                departure: stop.departure.map(event),
+                messages: stop
+                    .messages
+                    .into_iter()
+                    .map(|message| RisJourneyDetailsMessage {
+                        code: message.code,
+                        r#type: message.r#type,
+                        display_priority: message.display_priority,
+                        category: message.category,
+                        text: message.text,
+                        text_short: message.text_short,
+                    })
+                    .collect(),
+                disruptions: vec![],
+                transport: zugportal_transport(&train),
+                scheduled_platform: Some(stop.platform).filter(|platform| !platform.is_empty()),
+                real_platform: Some(stop.platform_predicted)
+                    .filter(|platform| !platform.is_empty()),
+                administration: {
+                    let operator = find_operator(
+                        &stop.administration.id,
+                        &stop.administration.operator_code,
+                        &stop.administration.operator_name,
+                    )
+                    .cloned();
+
+                    RisJourneyStopAdministration {
+                        name: operator
+                            .as_ref()
+                            .map(|operator| operator.display_name.clone())
+                            .unwrap_or_else(|| stop.administration.operator_name.clone()),
+                        id: stop.administration.id,
+                        operator_code: stop.administration.operator_code,
+                        ris_name: stop.administration.operator_name,
+                        operator,
+                    }
+                },
+            }
+        })
+        .collect();
+
+    Some(RisJourneyDetails {
+        id: response.journey_id,
+        journey_type,
+        origin_name,
+        origin_id,
+        destination_name,
+        destination_id,
+        cancelled,
+        stops,
+    })
+}
+
+fn zugportal_transport(train: &ZugportalJourneyTrain) -> RisTransport {
+    RisTransport {
+        r#type: train.r#type.to_owned(),
+        category: train.category.to_owned(),
+        number: train.no as i32,
+        line: Some(train.line_name.to_owned()).filter(|line| !line.is_empty()),
+        label: None,
+        replacement_transport: None,
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum ZugportalJourneyOrErrorResponse {
+    Response(Box<ZugportalJourneyResponse>),
+    Error(ZugportalError),
+}
+
 #[allow(dead_code)]
 #[derive(Deserialize, Debug)]
 #[serde(untagged)]
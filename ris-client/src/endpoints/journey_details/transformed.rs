@@ -3,6 +3,8 @@ use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::operators::RisOperator;
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct RisJourneyDetails {
@@ -54,6 +56,9 @@ pub struct RisJourneyStopAdministration {
     pub name: String,
     pub operator_code: String,
     pub ris_name: String,
+    /// The entry of the operator registry, if the operator is known
+    #[schema(nullable)]
+    pub operator: Option<RisOperator>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
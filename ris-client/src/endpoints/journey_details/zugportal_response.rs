@@ -0,0 +1,91 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+// The journey endpoint of Zugportal uses the same structure for stations, trains and administrations as its station board
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZugportalJourneyResponse {
+    #[serde(rename = "journeyID", alias = "journeyId")]
+    pub journey_id: String,
+    pub train: ZugportalJourneyTrain,
+    #[serde(default)]
+    pub canceled: bool,
+    #[serde(default)]
+    pub stops: Vec<ZugportalJourneyStop>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZugportalJourneyTrain {
+    #[serde(default)]
+    pub line_name: String,
+    pub no: u32,
+    pub category: String,
+    pub r#type: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZugportalJourneyStop {
+    pub station: ZugportalJourneyStation,
+    #[serde(default)]
+    pub platform: String,
+    #[serde(default)]
+    pub platform_predicted: String,
+    pub arrival: Option<ZugportalJourneyEvent>,
+    pub departure: Option<ZugportalJourneyEvent>,
+    #[serde(default)]
+    pub canceled: bool,
+    #[serde(default)]
+    pub additional: bool,
+    #[serde(default)]
+    pub on_demand: bool,
+    #[serde(default)]
+    pub messages: Vec<ZugportalJourneyMessage>,
+    /// Only set if the train changes its category or number at this stop
+    pub train: Option<ZugportalJourneyTrain>,
+    pub administration: ZugportalJourneyAdministration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZugportalJourneyStation {
+    pub eva_no: String,
+    pub name: String,
+    #[serde(default)]
+    pub canceled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZugportalJourneyEvent {
+    pub time: DateTime<FixedOffset>,
+    pub time_predicted: Option<DateTime<FixedOffset>>,
+    pub time_type: String,
+    #[serde(default)]
+    pub diff: i32,
+    #[serde(default)]
+    pub canceled: bool,
+    #[serde(default)]
+    pub additional: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZugportalJourneyMessage {
+    pub code: Option<String>,
+    pub r#type: String,
+    pub display_priority: Option<i32>,
+    pub category: Option<String>,
+    pub text: String,
+    pub text_short: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZugportalJourneyAdministration {
+    pub id: String,
+    pub operator_code: String,
+    pub operator_name: String,
+}
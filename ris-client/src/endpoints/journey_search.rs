@@ -3,7 +3,7 @@ use chrono::NaiveDate;
 pub use response::*;
 
 use crate::request::ResponseOrRisError;
-use crate::{RisClient, RisOrRequestError};
+use crate::{RisClient, RisEndpoint, RisOrRequestError};
 
 mod response;
 
@@ -17,9 +17,12 @@ impl RisClient {
         number: &str,
         date: Option<NaiveDate>,
     ) -> Result<RisJourneySearchResponse, RisOrRequestError> {
-        let _permit = self.semaphore.acquire().await;
+        let backend = self
+            .backends
+            .journey_search
+            .require(RisEndpoint::JourneySearch)?;
 
-        let url = format!("{}/db/apis/ris-journeys/v1/byrelation", self.base_url);
+        let _permit = self.semaphore.acquire().await;
 
         let number = urlencoding::encode(number);
 
@@ -33,12 +36,9 @@ impl RisClient {
             query.push(("date", date));
         }
 
-        let response: ResponseOrRisError<RisJourneySearchResponse> = self
-            .client
-            .get(&url)
+        let response: ResponseOrRisError<RisJourneySearchResponse> = backend
+            .get(&self.client, "/db/apis/ris-journeys/v1/byrelation")
             .query(&query)
-            .header("db-api-key", self.db_api_key.clone())
-            .header("db-client-id", self.db_client_id.clone())
             .send()
             .await?
             .json()
@@ -1,48 +1,71 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
-use chrono::DateTime;
+use chrono::{DateTime, Duration, FixedOffset};
 use chrono_tz::Tz;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 pub use transformed::*;
 
-use crate::helpers::name_from_administation_code;
+use crate::operators::find_operator;
 use crate::station_board::response::{StationBoardItem, StationBoardResponse};
-use crate::{RisClient, RisOrRequestError, ZugportalError};
+use crate::{RisBackend, RisClient, RisEndpoint, RisOrRequestError, ZugportalError};
 
-mod response;
+pub mod response;
 mod transformed;
 
+/// The longest time frame (in minutes) that is requested from Zugportal at once, longer time frames are requested in chunks.
+///
+/// Zugportal does not document a maximum time frame, so this is a conservative choice rather than a measured limit: \
+/// one hour keeps every response small enough that it is not truncated, at the cost of an extra request per hour.
+pub const MAX_TIME_FRAME_MINUTES: i64 = 60;
+
+/// How Zugportal expands the requested time frame, e.G. to include trains that are scheduled before the start but are delayed into the time frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExpandTimeFrame {
+    /// Expand the time frame at its start
+    #[default]
+    TimeStart,
+    /// Expand the time frame at its end
+    TimeEnd,
+    /// Return exactly the requested time frame
+    None,
+}
+
+impl ExpandTimeFrame {
+    fn as_query(self) -> Option<&'static str> {
+        match self {
+            ExpandTimeFrame::TimeStart => Some("TIME_START"),
+            ExpandTimeFrame::TimeEnd => Some("TIME_END"),
+            ExpandTimeFrame::None => None,
+        }
+    }
+}
+
 // This endpoint uses the "zugportal" api that the "Zugportal" App uses, and its basically ris so I didnt feel like making another package for it
 // Update 01/2025: The only known ris api key has been invalidated, so it will stay with Zugportal
 
 impl RisClient {
+    /// Get the arrivals and departures of a station in the specified time frame.
+    ///
+    /// Time frames longer than [`MAX_TIME_FRAME_MINUTES`] are requested in chunks, so long time frames are not truncated.
     pub async fn station_board(
         &self,
         eva: &str,
         time_start: Option<DateTime<Tz>>,
         time_end: Option<DateTime<Tz>>,
+        expand_time_frame: ExpandTimeFrame,
     ) -> Result<RisStationBoard, RisOrRequestError> {
         let (arrivals, departures) = tokio::join!(
-            self.station_board_arrivals(eva, time_start, time_end),
-            self.station_board_departures(eva, time_start, time_end)
+            self.station_board_arrivals(eva, time_start, time_end, expand_time_frame),
+            self.station_board_departures(eva, time_start, time_end, expand_time_frame)
         );
 
         let arrivals = arrivals?;
         let departures = departures?;
 
-        let mut trains: BTreeMap<String, (Option<StationBoardItem>, Option<StationBoardItem>)> =
-            BTreeMap::new();
-
-        for train in arrivals.items {
-            let id = train.train.journey_id.to_owned();
-            trains.entry(id).or_insert_with(|| (None, None)).0 = Some(train);
-        }
-
-        for train in departures.items {
-            let id = train.train.journey_id.to_owned();
-            trains.entry(id).or_insert_with(|| (None, None)).1 = Some(train);
-        }
+        let trains = pair_stops(arrivals.items, departures.items);
 
         if departures.station_name.is_none() {
             return Err(RisOrRequestError::NotFoundError);
@@ -55,7 +78,7 @@ impl RisClient {
             time_end: departures.time_end,
             items: trains
                 .into_iter()
-                .map(|(id, (arrival, departure))| {
+                .map(|((id, _), (arrival, departure))| {
                     let departure_arrival = departure
                         .clone()
                         .unwrap_or_else(|| arrival.clone().unwrap());
@@ -120,16 +143,25 @@ impl RisClient {
                             .unwrap_or(departure_arrival.station.name),
                         platform_scheduled: scheduled_platform,
                         platform_realtime: realtime_platform,
-                        administation: RisStationBoardItemAdministration {
-                            id: departure_arrival.administration.id,
-                            operator_code: departure_arrival.administration.operator_code,
-                            operator_name: String::from(
-                                name_from_administation_code(
-                                    &departure_arrival.administration.operator_name,
-                                )
-                                .unwrap_or(&departure_arrival.administration.operator_name),
-                            ),
-                            ris_operator_name: departure_arrival.administration.operator_name,
+                        administation: {
+                            let administration = departure_arrival.administration;
+                            let operator = find_operator(
+                                &administration.id,
+                                &administration.operator_code,
+                                &administration.operator_name,
+                            )
+                            .cloned();
+
+                            RisStationBoardItemAdministration {
+                                operator_name: operator
+                                    .as_ref()
+                                    .map(|operator| operator.display_name.clone())
+                                    .unwrap_or_else(|| administration.operator_name.clone()),
+                                id: administration.id,
+                                operator_code: administration.operator_code,
+                                ris_operator_name: administration.operator_name,
+                                operator,
+                            }
                         },
                     }
                 })
@@ -142,63 +174,246 @@ impl RisClient {
         eva: &str,
         time_start: Option<DateTime<Tz>>,
         time_end: Option<DateTime<Tz>>,
+        expand_time_frame: ExpandTimeFrame,
     ) -> Result<StationBoardResponse, RisOrRequestError> {
-        let _permit = self.semaphore.acquire().await;
+        self.paginated_station_board(
+            &format!("/ri/board/departure/{eva}"),
+            time_start,
+            time_end,
+            expand_time_frame,
+        )
+        .await
+    }
 
-        let url = format!(
-            "https://zugportal.de/@prd/zupo-travel-information/api/public/ri/board/departure/{eva}"
+    pub async fn station_board_arrivals(
+        &self,
+        eva: &str,
+        time_start: Option<DateTime<Tz>>,
+        time_end: Option<DateTime<Tz>>,
+        expand_time_frame: ExpandTimeFrame,
+    ) -> Result<StationBoardResponse, RisOrRequestError> {
+        self.paginated_station_board(
+            &format!("/ri/board/arrival/{eva}"),
+            time_start,
+            time_end,
+            expand_time_frame,
+        )
+        .await
+    }
+
+    /// Requests time frames longer than [`MAX_TIME_FRAME_MINUTES`] in multiple chunks and merges them.
+    ///
+    /// Only the first chunk is expanded at its start and only the last chunk at its end, \
+    /// the chunks in between overlap at their borders which is resolved by de-duplicating the stops.
+    async fn paginated_station_board(
+        &self,
+        path: &str,
+        time_start: Option<DateTime<Tz>>,
+        time_end: Option<DateTime<Tz>>,
+        expand_time_frame: ExpandTimeFrame,
+    ) -> Result<StationBoardResponse, RisOrRequestError> {
+        let backend = self
+            .backends
+            .station_board
+            .require(RisEndpoint::StationBoard)?;
+
+        let (Some(time_start), Some(time_end)) = (time_start, time_end) else {
+            return self
+                .station_board_page(backend, path, time_start, time_end, expand_time_frame)
+                .await;
+        };
+
+        let chunks = time_frame_chunks(
+            time_start,
+            time_end,
+            Duration::minutes(MAX_TIME_FRAME_MINUTES),
         );
+        let last = chunks.len() - 1;
 
-        station_board(self, url, time_start, time_end).await
+        let pages = futures::future::join_all(chunks.into_iter().enumerate().map(
+            |(index, (chunk_start, chunk_end))| {
+                let expand_time_frame = match expand_time_frame {
+                    ExpandTimeFrame::TimeStart if index == 0 => ExpandTimeFrame::TimeStart,
+                    ExpandTimeFrame::TimeEnd if index == last => ExpandTimeFrame::TimeEnd,
+                    _ => ExpandTimeFrame::None,
+                };
+
+                self.station_board_page(
+                    backend,
+                    path,
+                    Some(chunk_start),
+                    Some(chunk_end),
+                    expand_time_frame,
+                )
+            },
+        ))
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+        merge_station_board_pages(pages).ok_or(RisOrRequestError::NotFoundError)
     }
 
-    pub async fn station_board_arrivals(
+    async fn station_board_page(
         &self,
-        eva: &str,
+        backend: &RisBackend,
+        path: &str,
         time_start: Option<DateTime<Tz>>,
         time_end: Option<DateTime<Tz>>,
+        expand_time_frame: ExpandTimeFrame,
     ) -> Result<StationBoardResponse, RisOrRequestError> {
         let _permit = self.semaphore.acquire().await;
 
-        let url = format!(
-            "https://zugportal.de/@prd/zupo-travel-information/api/public/ri/board/arrival/{eva}"
-        );
+        let mut query = Vec::new();
+
+        if let Some(expand_time_frame) = expand_time_frame.as_query() {
+            query.push(("expandTimeFrame", expand_time_frame.to_owned()));
+        }
+
+        if let Some(time_start) = time_start {
+            query.push(("timeStart", time_start.to_rfc3339()))
+        }
 
-        station_board(self, url, time_start, time_end).await
+        if let Some(time_end) = time_end {
+            query.push(("timeEnd", time_end.to_rfc3339()))
+        }
+
+        let response: RisStationBoardOrErrorResponse = backend
+            .get(&self.client, path)
+            .query(&query)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        match response {
+            RisStationBoardOrErrorResponse::Response(response) => Ok(*response),
+            RisStationBoardOrErrorResponse::Error(error) => {
+                Err(RisOrRequestError::ZugportalError(error))
+            }
+        }
     }
 }
 
-async fn station_board(
-    client: &RisClient,
-    url: String,
-    time_start: Option<DateTime<Tz>>,
-    time_end: Option<DateTime<Tz>>,
-) -> Result<StationBoardResponse, RisOrRequestError> {
-    let mut query = vec![("expandTimeFrame", "TIME_START".to_owned())]; // todo: find out what this means because I have no idea
+/// The arrival and the departure of a journey at the station, keyed by the journey id and the scheduled time of the stop.
+type PairedStops =
+    BTreeMap<(String, DateTime<FixedOffset>), (Option<StationBoardItem>, Option<StationBoardItem>)>;
 
-    if let Some(time_start) = time_start {
-        query.push(("timeStart", time_start.to_rfc3339()))
+/// Pairs every arrival with the departure of the same journey that follows it.
+///
+/// Journeys that call at the station more than once (e.G. circular or turnaround services) are kept once per call.
+fn pair_stops(arrivals: Vec<StationBoardItem>, departures: Vec<StationBoardItem>) -> PairedStops {
+    let mut journeys: BTreeMap<String, (Vec<StationBoardItem>, Vec<StationBoardItem>)> =
+        BTreeMap::new();
+
+    for arrival in arrivals {
+        let id = arrival.train.journey_id.to_owned();
+        journeys.entry(id).or_default().0.push(arrival);
     }
 
-    if let Some(time_end) = time_end {
-        query.push(("timeEnd", time_end.to_rfc3339()))
+    for departure in departures {
+        let id = departure.train.journey_id.to_owned();
+        journeys.entry(id).or_default().1.push(departure);
     }
 
-    let response: RisStationBoardOrErrorResponse = client
-        .client
-        .get(&url)
-        .query(&query)
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    match response {
-        RisStationBoardOrErrorResponse::Response(response) => Ok(*response),
-        RisStationBoardOrErrorResponse::Error(error) => {
-            Err(RisOrRequestError::ZugportalError(error))
+    let mut stops = BTreeMap::new();
+
+    for (id, (mut arrivals, mut departures)) in journeys {
+        arrivals.sort_by_key(|arrival| arrival.time);
+        departures.sort_by_key(|departure| departure.time);
+
+        let mut arrivals = arrivals.into_iter().peekable();
+        let mut departures = departures.into_iter().peekable();
+
+        loop {
+            // A departure before the next arrival belongs to an arrival outside of the time frame
+            let departure_first = match (arrivals.peek(), departures.peek()) {
+                (None, None) => break,
+                (Some(arrival), Some(departure)) => departure.time < arrival.time,
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+            };
+
+            let arrival = if departure_first {
+                None
+            } else {
+                arrivals.next()
+            };
+
+            // The journey ended at the arrival if it arrives again before it departs
+            let ended = match (arrivals.peek(), departures.peek()) {
+                (Some(next_arrival), Some(departure)) => {
+                    arrival.is_some() && next_arrival.time <= departure.time
+                }
+                _ => false,
+            };
+
+            let departure = if ended { None } else { departures.next() };
+
+            let time = arrival
+                .as_ref()
+                .or(departure.as_ref())
+                .map(|stop| stop.time)
+                .expect("a stop has an arrival or a departure");
+
+            stops.insert((id.clone(), time), (arrival, departure));
+        }
+    }
+
+    stops
+}
+
+/// Splits the time frame from `time_start` to `time_end` into consecutive chunks that are at most `max_length` long.
+///
+/// Always returns at least one chunk, even if `time_end` is before `time_start`.
+pub fn time_frame_chunks(
+    time_start: DateTime<Tz>,
+    time_end: DateTime<Tz>,
+    max_length: Duration,
+) -> Vec<(DateTime<Tz>, DateTime<Tz>)> {
+    let mut chunks = Vec::new();
+    let mut chunk_start = time_start;
+
+    while time_end - chunk_start > max_length {
+        let chunk_end = chunk_start + max_length;
+        chunks.push((chunk_start, chunk_end));
+        chunk_start = chunk_end;
+    }
+
+    chunks.push((chunk_start, time_end));
+
+    chunks
+}
+
+/// Merges the responses for consecutive chunks of a time frame into one response.
+///
+/// Stops that are in multiple chunks are only kept once (the first occurrence), returns `None` if there are no pages.
+///
+/// A stop is identified by its journey and its time, so circular or turnaround services that call at the station twice keep both calls.
+pub fn merge_station_board_pages(pages: Vec<StationBoardResponse>) -> Option<StationBoardResponse> {
+    let mut pages = pages.into_iter();
+    let mut merged = pages.next()?;
+
+    let mut stops = merged
+        .items
+        .iter()
+        .map(|item| (item.train.journey_id.to_owned(), item.time))
+        .collect::<HashSet<_>>();
+
+    for page in pages {
+        merged.eva_no = merged.eva_no.or(page.eva_no);
+        merged.station_name = merged.station_name.or(page.station_name);
+        merged.time_start = merged.time_start.min(page.time_start);
+        merged.time_end = merged.time_end.max(page.time_end);
+
+        for item in page.items {
+            if stops.insert((item.train.journey_id.to_owned(), item.time)) {
+                merged.items.push(item);
+            }
         }
     }
+
+    Some(merged)
 }
 
 #[derive(Deserialize, Debug)]
@@ -2,6 +2,8 @@ use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::operators::RisOperator;
+
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct RisStationBoard {
@@ -50,6 +52,9 @@ pub struct RisStationBoardItemAdministration {
     pub operator_code: String,
     pub operator_name: String,
     pub ris_operator_name: String,
+    /// The entry of the operator registry, if the operator is known
+    #[schema(nullable)]
+    pub operator: Option<RisOperator>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, ToSchema, Clone)]
@@ -2,9 +2,9 @@ pub use transformed::*;
 
 use crate::request::ResponseOrRisError;
 use crate::station_information::response::StationInformationResponse;
-use crate::{RisClient, RisOrRequestError};
+use crate::{RisClient, RisEndpoint, RisOrRequestError};
 
-pub(crate) mod response;
+pub mod response;
 mod transformed;
 
 impl RisClient {
@@ -15,18 +15,18 @@ impl RisClient {
         &self,
         eva: &str,
     ) -> Result<Option<RisStationInformation>, RisOrRequestError> {
-        let _permit = self.semaphore.acquire().await;
+        let backend = self
+            .backends
+            .station_information
+            .require(RisEndpoint::StationInformation)?;
 
-        let url = format!(
-            "{}/db/apis/ris-stations/v1/stop-places/{eva}",
-            self.base_url
-        );
+        let _permit = self.semaphore.acquire().await;
 
-        let response: ResponseOrRisError<StationInformationResponse> = self
-            .client
-            .get(&url)
-            .header("db-api-key", &self.db_api_key)
-            .header("db-client-id", &self.db_client_id)
+        let response: ResponseOrRisError<StationInformationResponse> = backend
+            .get(
+                &self.client,
+                &format!("/db/apis/ris-stations/v1/stop-places/{eva}"),
+            )
             .send()
             .await?
             .json()
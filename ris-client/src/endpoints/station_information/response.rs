@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -25,12 +27,36 @@ pub struct StationInformation {
     pub position: Position,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, ToSchema)]
-#[serde(rename_all = "UPPERCASE")]
-pub struct Translatable<T> {
-    pub de: T,
+/// A value in every language RIS provides, keyed by the uppercase language code (e.G. `DE` or `EN`).
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone)]
+#[serde(transparent)]
+pub struct Translatable<T>(pub BTreeMap<String, T>);
+
+impl<T> Translatable<T> {
+    /// The value in the specified language, ignoring the case of the language code.
+    pub fn get(&self, language: &str) -> Option<&T> {
+        self.0.get(&language.to_uppercase())
+    }
+
+    /// The value in the specified language, falling back to German and then to any language.
+    pub fn preferred(&self, language: &str) -> Option<&T> {
+        preferred_translation(&self.0, language)
+    }
 }
 
+pub(crate) fn preferred_translation<'a, T>(
+    translations: &'a BTreeMap<String, T>,
+    language: &str,
+) -> Option<&'a T> {
+    translations
+        .get(&language.to_uppercase())
+        .or_else(|| translations.get(GERMAN))
+        .or_else(|| translations.values().next())
+}
+
+/// The language RIS always provides and every lookup falls back to
+pub const GERMAN: &str = "DE";
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StationNameContent {
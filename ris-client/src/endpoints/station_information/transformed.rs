@@ -1,15 +1,32 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::station_information::response::{preferred_translation, GERMAN};
+
 impl From<crate::station_information::response::StationInformation> for RisStationInformation {
     fn from(value: crate::station_information::response::StationInformation) -> Self {
-        Self {
+        let name_translations: BTreeMap<String, RisStationNameContent> = value
+            .names
+            .0
+            .into_iter()
+            .map(|(language, name)| {
+                (
+                    language,
+                    RisStationNameContent {
+                        name_long: name.name_long,
+                        speech_long: name.speech_long,
+                        speech_short: name.speech_short,
+                    },
+                )
+            })
+            .collect();
+
+        let mut station = Self {
             eva: value.eva_number,
-            names: RisStationNameContent {
-                name_long: value.names.de.name_long,
-                speech_long: value.names.de.speech_long,
-                speech_short: value.names.de.speech_short,
-            },
+            names: RisStationNameContent::default(),
+            name_translations,
             station_id: value.station_id,
             available_transports: value.available_transports,
             transport_associations: value.transport_associations,
@@ -17,12 +34,28 @@ impl From<crate::station_information::response::StationInformation> for RisStati
             state: value.state,
             municipality_key: value.municipality_key,
             time_zone: value.time_zone,
-            metropolis: value.metropolis.map(|m| m.de),
+            metropolis: None,
+            metropolis_translations: value.metropolis.map(|m| m.0).unwrap_or_default(),
             position: RisPosition {
                 longitude: value.position.longitude,
                 latitude: value.position.latitude,
             },
+        };
+
+        station.localize(GERMAN);
+
+        station
+    }
+}
+
+impl RisStationInformation {
+    /// Sets `names` and `metropolis` to the specified language, falling back to German if RIS does not provide it.
+    pub fn localize(&mut self, language: &str) {
+        if let Some(names) = preferred_translation(&self.name_translations, language) {
+            self.names = names.clone();
         }
+
+        self.metropolis = preferred_translation(&self.metropolis_translations, language).cloned();
     }
 }
 
@@ -32,8 +65,13 @@ pub struct RisStationInformation {
     pub eva: String,
     #[schema(nullable)]
     pub station_id: Option<String>,
+    /// The names in the requested language
     pub names: RisStationNameContent,
+    /// The names in every language RIS provides, keyed by the uppercase language code
+    pub name_translations: BTreeMap<String, RisStationNameContent>,
+    #[schema(nullable)]
     pub metropolis: Option<String>,
+    pub metropolis_translations: BTreeMap<String, String>,
     pub available_transports: Vec<String>,
     pub transport_associations: Vec<String>,
     pub country_code: String,
@@ -43,7 +81,7 @@ pub struct RisStationInformation {
     pub position: RisPosition,
 }
 
-#[derive(Serialize, Deserialize, Debug, ToSchema, PartialEq, Clone)]
+#[derive(Serialize, Deserialize, Debug, ToSchema, PartialEq, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct RisStationNameContent {
     pub name_long: String,
@@ -1,7 +1,8 @@
 pub use response::*;
 
 use crate::request::ResponseOrRisError;
-use crate::{RisClient, RisOrRequestError};
+use crate::station_information::response::GERMAN;
+use crate::{RisClient, RisEndpoint, RisOrRequestError};
 
 mod response;
 
@@ -14,30 +15,37 @@ impl RisClient {
         query: &str,
         limit: Option<u32>,
     ) -> Result<Vec<RisStationSearchElement>, RisOrRequestError> {
+        let backend = self
+            .backends
+            .station_search
+            .require(RisEndpoint::StationSearch)?;
+
         let _permit = self.semaphore.acquire().await;
 
         let limit = limit.unwrap_or(25);
 
         let query = urlencoding::encode(query);
 
-        let url = format!(
-            "{}/db/apis/ris-stations/v1/stop-places/by-name/{query}",
-            self.base_url
-        );
-
-        let response: ResponseOrRisError<RisStationSearchResponse> = self
-            .client
-            .get(&url)
+        let response: ResponseOrRisError<RisStationSearchResponse> = backend
+            .get(
+                &self.client,
+                &format!("/db/apis/ris-stations/v1/stop-places/by-name/{query}"),
+            )
             .query(&[("limit", format!("{}", limit))])
-            .header("db-api-key", &self.db_api_key)
-            .header("db-client-id", &self.db_client_id)
             .send()
             .await?
             .json()
             .await?;
 
         match response {
-            ResponseOrRisError::Response(response) => Ok(response.stop_places),
+            ResponseOrRisError::Response(response) => Ok(response
+                .stop_places
+                .into_iter()
+                .map(|mut station| {
+                    station.localize(GERMAN);
+                    station
+                })
+                .collect()),
             ResponseOrRisError::Error(error) => Err(RisOrRequestError::RisError(error)),
             ResponseOrRisError::UnauthorizedError(error) => {
                 Err(RisOrRequestError::RisUnauthorizedError(error))
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+
+use crate::station_information::response::preferred_translation;
 use crate::station_information::RisPosition;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -17,14 +20,36 @@ pub struct RisStationSearchElement {
     pub station_id: Option<String>,
     pub group_members: Vec<String>,
     pub names: RisStationSearchTranslatable,
+    /// The name in the requested language, not part of the RIS response
+    #[serde(default)]
+    #[schema(nullable)]
+    pub name: Option<RisStationSearchNameContent>,
     pub available_transports: Vec<String>,
     pub position: RisPosition,
 }
 
+/// The names of a station in every language RIS provides, keyed by the uppercase language code (e.G. `DE` or `EN`).
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, ToSchema)]
-#[serde(rename_all = "UPPERCASE")]
-pub struct RisStationSearchTranslatable {
-    pub de: RisStationSearchNameContent,
+#[serde(transparent)]
+pub struct RisStationSearchTranslatable(pub BTreeMap<String, RisStationSearchNameContent>);
+
+impl RisStationSearchTranslatable {
+    /// The name in the specified language, ignoring the case of the language code.
+    pub fn get(&self, language: &str) -> Option<&RisStationSearchNameContent> {
+        self.0.get(&language.to_uppercase())
+    }
+
+    /// The name in the specified language, falling back to German and then to any language.
+    pub fn preferred(&self, language: &str) -> Option<&RisStationSearchNameContent> {
+        preferred_translation(&self.0, language)
+    }
+}
+
+impl RisStationSearchElement {
+    /// Sets `name` to the specified language, falling back to German if RIS does not provide it.
+    pub fn localize(&mut self, language: &str) {
+        self.name = self.names.preferred(language).cloned();
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, ToSchema)]
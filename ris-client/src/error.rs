@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use utoipa::ToSchema;
 
+use crate::RisBackendKind;
+
 #[derive(Serialize, Deserialize, Debug, Error, ToSchema)]
 #[error("Ris returned an error.")]
 #[serde(rename_all = "camelCase")]
@@ -38,6 +40,13 @@ pub struct ZugportalError {
     pub message: String,
 }
 
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("The {endpoint} endpoint is not supported by the {backend} backend.")]
+pub struct UnsupportedBackendError {
+    pub endpoint: &'static str,
+    pub backend: RisBackendKind,
+}
+
 #[derive(Error, Debug)]
 pub enum RisOrRequestError {
     #[error("Ris returned an error.")]
@@ -49,5 +58,7 @@ pub enum RisOrRequestError {
     #[error("There was nothing found with these parameters")]
     NotFoundError,
     #[error(transparent)]
+    UnsupportedBackend(#[from] UnsupportedBackendError),
+    #[error(transparent)]
     FailedRequest(#[from] reqwest::Error),
 }
@@ -1,7 +1,9 @@
 use async_lock::Semaphore;
 
+mod backend;
 mod error;
-mod helpers;
+pub mod operators;
+pub use backend::*;
 pub use error::*;
 
 mod endpoints;
@@ -12,29 +14,46 @@ use reqwest::{Certificate, Client, Proxy};
 
 pub struct RisClient {
     client: reqwest::Client,
-    base_url: String,
     semaphore: Semaphore,
-    db_client_id: String,
-    db_api_key: String,
+    backends: RisBackends,
 }
 
 impl RisClient {
+    /// Uses Zugportal for every endpoint it supports and the DB API Marketplace at `base_url` for the rest.
     pub fn new(
         client: Option<reqwest::Client>,
         base_url: Option<String>,
         concurrent_requests: Option<usize>,
         db_client_id: &str,
         db_api_key: &str,
+    ) -> Self {
+        Self::with_backends(
+            client,
+            concurrent_requests,
+            RisBackends::new(
+                RisBackend::zugportal(None),
+                RisBackend::db_api_marketplace(base_url, db_client_id, db_api_key),
+            ),
+        )
+    }
+
+    /// Uses the specified backend for every endpoint.
+    pub fn with_backends(
+        client: Option<reqwest::Client>,
+        concurrent_requests: Option<usize>,
+        backends: RisBackends,
     ) -> Self {
         Self {
             client: client.unwrap_or_default(),
-            base_url: base_url.unwrap_or_else(|| String::from("https://apis.deutschebahn.com")),
             semaphore: Semaphore::new(concurrent_requests.unwrap_or(100)),
-            db_client_id: db_client_id.to_string(),
-            db_api_key: db_api_key.to_string(),
+            backends,
         }
     }
 
+    pub fn backends(&self) -> &RisBackends {
+        &self.backends
+    }
+
     pub fn default_debug(proxy: &str, pem: &[u8], db_client_id: &str, db_api_key: &str) -> Self {
         let http_client = Client::builder()
             .add_root_certificate(Certificate::from_pem(pem).unwrap())
@@ -0,0 +1,88 @@
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+static OPERATORS: OnceLock<Vec<RisOperator>> = OnceLock::new();
+
+/// An operator of trains as listed in the bundled operator registry.
+///
+/// The registry lists DB Fernverkehr, the regional companies of the DB group, the foreign state railways and the larger regional operators.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RisOperator {
+    /// The administration id RIS uses for the operator, e.G. `80` for DB Fernverkehr
+    ///
+    /// Only set for operators RIS reliably reports with their own administration id, \
+    /// all other operators are found by their name or their operator code.
+    #[schema(nullable)]
+    pub administration_id: Option<String>,
+    pub operator_code: String,
+    pub short_name: String,
+    pub name: String,
+    /// The name shown as the operator name of RIS results, e.G. `SNCF`
+    pub display_name: String,
+    /// The ISO 3166-1 alpha-2 code of the country the operator is based in
+    pub country: String,
+    #[schema(nullable)]
+    pub website: Option<String>,
+}
+
+/// All operators of the bundled registry.
+pub fn operators() -> &'static [RisOperator] {
+    OPERATORS.get_or_init(|| {
+        serde_json::from_str(include_str!("../data/operators.json"))
+            .expect("the bundled operator registry is valid")
+    })
+}
+
+pub fn operator_by_administration_id(administration_id: &str) -> Option<&'static RisOperator> {
+    operators()
+        .iter()
+        .find(|operator| operator.administration_id.as_deref() == Some(administration_id))
+}
+
+/// Operators that share their code (e.G. the companies of the DB group) are found by the first of them in the registry.
+pub fn operator_by_code(operator_code: &str) -> Option<&'static RisOperator> {
+    operators()
+        .iter()
+        .find(|operator| operator.operator_code.eq_ignore_ascii_case(operator_code))
+}
+
+pub fn operator_by_name(name: &str) -> Option<&'static RisOperator> {
+    operators()
+        .iter()
+        .find(|operator| operator.name.eq_ignore_ascii_case(name))
+}
+
+/// Looks up the operator of an administration returned by RIS, first by its id, then by its name \
+/// and then by its operator code if only one operator in the registry has that code.
+pub fn find_operator(
+    administration_id: &str,
+    operator_code: &str,
+    operator_name: &str,
+) -> Option<&'static RisOperator> {
+    operator_by_administration_id(administration_id)
+        .or_else(|| operator_by_name(operator_name))
+        .or_else(|| {
+            let mut operators = operators()
+                .iter()
+                .filter(|operator| operator.operator_code.eq_ignore_ascii_case(operator_code));
+
+            operators.next().filter(|_| operators.next().is_none())
+        })
+}
+
+/// All operators whose operator code, short name or name contains the query, ignoring case.
+pub fn search_operators(query: &str) -> Vec<&'static RisOperator> {
+    let query = query.to_lowercase();
+
+    operators()
+        .iter()
+        .filter(|operator| {
+            operator.operator_code.to_lowercase().contains(&query)
+                || operator.short_name.to_lowercase().contains(&query)
+                || operator.name.to_lowercase().contains(&query)
+        })
+        .collect()
+}
@@ -0,0 +1,171 @@
+use ris_client::{
+    RisBackend, RisBackendKind, RisBackends, RisClient, RisEndpoint, RisOrRequestError,
+    UnsupportedBackendError,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+const JOURNEY: &str = include_str!("fixtures/zugportal_journey.json");
+
+/// Starts a stand-in server that answers exactly one request with `body` and returns the head of that request.
+async fn stand_in_server(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in server");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.expect("No request received");
+
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = stream
+                .read(&mut buffer)
+                .await
+                .expect("Failed to read request");
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+        }
+
+        let response = format!(
+            "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream
+            .write_all(response.as_bytes())
+            .await
+            .expect("Failed to write response");
+
+        String::from_utf8_lossy(&request).to_lowercase()
+    });
+
+    (url, handle)
+}
+
+#[tokio::test]
+async fn zugportal_backend_uses_its_base_url() {
+    let (url, request) = stand_in_server("200 OK", JOURNEY).await;
+
+    let zugportal = RisBackend::zugportal(Some(url));
+    let db_api_marketplace = RisBackend::db_api_marketplace(None, "client-id", "api-key");
+
+    let ris_client =
+        RisClient::with_backends(None, None, RisBackends::new(zugportal, db_api_marketplace));
+
+    let journey_details = ris_client
+        .journey_details("20231018-5e3e1a3b")
+        .await
+        .expect("Failed to get journey details from stand-in server");
+
+    assert_eq!(journey_details.origin_name, "Hamburg-Altona");
+
+    let request = request.await.unwrap();
+    assert!(request.starts_with("get /ri/journey/20231018-5e3e1a3b "));
+    assert!(!request.contains("db-api-key"));
+}
+
+#[tokio::test]
+async fn db_api_marketplace_backend_authenticates() {
+    let (url, request) = stand_in_server(
+        "401 Unauthorized",
+        r#"{"httpCode":"401","httpMessage":"Unauthorized","moreInformation":"Invalid client id or secret"}"#,
+    )
+    .await;
+
+    let db_api_marketplace = RisBackend::db_api_marketplace(Some(url), "client-id", "api-key");
+
+    let ris_client = RisClient::with_backends(
+        None,
+        None,
+        RisBackends::new(RisBackend::zugportal(None), db_api_marketplace),
+    );
+
+    #[allow(deprecated)]
+    let result = ris_client
+        .station_search_by_name("Frankfurt", Some(1))
+        .await;
+
+    assert!(
+        matches!(result, Err(RisOrRequestError::RisUnauthorizedError(_))),
+        "Unexpected result: {result:?}"
+    );
+
+    let request = request.await.unwrap();
+    assert!(
+        request.starts_with("get /db/apis/ris-stations/v1/stop-places/by-name/frankfurt?limit=1 ")
+    );
+    assert!(request.contains("db-api-key: api-key"));
+    assert!(request.contains("db-client-id: client-id"));
+}
+
+#[tokio::test]
+async fn unsupported_backends_are_rejected() {
+    let zugportal = RisBackend::zugportal(Some(String::from("http://127.0.0.1:9")));
+
+    let ris_client =
+        RisClient::with_backends(None, None, RisBackends::new(zugportal.clone(), zugportal));
+
+    #[allow(deprecated)]
+    let result = ris_client.station_information("8000105").await;
+
+    assert!(
+        matches!(
+            result,
+            Err(RisOrRequestError::UnsupportedBackend(
+                UnsupportedBackendError {
+                    backend: RisBackendKind::Zugportal,
+                    ..
+                }
+            ))
+        ),
+        "Unexpected result: {result:?}"
+    );
+}
+
+#[test]
+fn backend_configurations_are_validated() {
+    let zugportal = RisBackend::zugportal(None);
+    let db_api_marketplace = RisBackend::db_api_marketplace(None, "client-id", "api-key");
+
+    let mut backends = RisBackends::new(zugportal, db_api_marketplace.clone());
+    assert_eq!(backends.validate(), Ok(()));
+
+    backends.journey_details = db_api_marketplace.clone();
+    assert_eq!(backends.validate(), Ok(()));
+
+    backends.station_board = db_api_marketplace;
+    assert_eq!(
+        backends.validate(),
+        Err(UnsupportedBackendError {
+            endpoint: RisEndpoint::StationBoard.name(),
+            backend: RisBackendKind::DbApiMarketplace,
+        })
+    );
+}
+
+#[test]
+fn credentials_are_redacted() {
+    let db_api_marketplace =
+        RisBackend::db_api_marketplace(None, "secret-client-id", "secret-api-key");
+    let backends = RisBackends::new(RisBackend::zugportal(None), db_api_marketplace);
+
+    let formatted = format!("{backends:?}");
+
+    assert!(formatted.contains("https://apis.deutschebahn.com"));
+    assert!(!formatted.contains("secret-client-id"));
+    assert!(!formatted.contains("secret-api-key"));
+}
+
+#[test]
+fn backend_kinds_are_parsed() {
+    assert_eq!("zugportal".parse(), Ok(RisBackendKind::Zugportal));
+    assert_eq!(
+        "DB-API-Marketplace".parse(),
+        Ok(RisBackendKind::DbApiMarketplace)
+    );
+    assert!("hafas".parse::<RisBackendKind>().is_err());
+}
@@ -1,9 +1,11 @@
 use chrono::{TimeZone, Utc};
 use chrono_tz::Europe::Berlin;
 use dotenvy::dotenv;
+use ris_client::station_board::ExpandTimeFrame;
 use ris_client::RisClient;
 
 #[tokio::test]
+#[allow(deprecated)]
 async fn journey_details() {
     dotenv().ok();
 
@@ -19,6 +21,7 @@ async fn journey_details() {
             "8000105",
             Some(current),
             Some(current + chrono::Duration::hours(1)),
+            ExpandTimeFrame::TimeStart,
         )
         .await;
 
@@ -3,6 +3,7 @@ use iris_client::IrisClient;
 use ris_client::RisClient;
 
 #[tokio::test]
+#[allow(deprecated)]
 pub async fn journey_search() {
     dotenv().ok();
 
@@ -0,0 +1,89 @@
+use ris_client::operators::{
+    find_operator, operator_by_administration_id, operator_by_code, operators, search_operators,
+};
+
+#[test]
+fn registry_is_valid() {
+    let operators = operators();
+
+    assert!(!operators.is_empty());
+
+    for (index, operator) in operators.iter().enumerate() {
+        assert!(!operator.name.is_empty());
+        assert!(!operator.short_name.is_empty());
+        assert_eq!(operator.country.len(), 2, "{}", operator.name);
+
+        assert!(!operator.display_name.is_empty());
+
+        let duplicate = operators[index + 1..].iter().any(|other| {
+            other.administration_id.is_some()
+                && other.administration_id == operator.administration_id
+                || other.name == operator.name
+        });
+
+        assert!(!duplicate, "{} is listed twice", operator.name);
+    }
+}
+
+#[test]
+fn operators_are_found_by_administration_id() {
+    let operator = operator_by_administration_id("80").expect("DB Fernverkehr is missing");
+    assert_eq!(operator.name, "DB Fernverkehr AG");
+
+    let operator = operator_by_administration_id("81").expect("ÖBB is missing");
+    assert_eq!(operator.short_name, "ÖBB");
+    assert_eq!(operator.country, "AT");
+
+    assert!(operator_by_administration_id("ME").is_none());
+}
+
+#[test]
+fn operators_are_found_by_operator_code() {
+    let operator = operator_by_code("me").expect("metronom is missing");
+    assert_eq!(operator.short_name, "metronom");
+
+    // DB shares its code across its companies, DB Fernverkehr is listed first
+    let operator = operator_by_code("DB").expect("DB is missing");
+    assert_eq!(operator.administration_id.as_deref(), Some("80"));
+
+    let operator = find_operator("800292", "ME", "metronom").expect("metronom is missing");
+    assert_eq!(operator.operator_code, "ME");
+
+    // A shared code does not identify a company of the DB group
+    assert!(find_operator("800292", "DB", "DB Unbekannt GmbH").is_none());
+}
+
+#[test]
+fn db_group_companies_are_found_by_name() {
+    let operator =
+        find_operator("800337", "DB", "DB Regio AG Nordost").expect("DB Regio Nordost is missing");
+    assert_eq!(operator.short_name, "DB Regio Nordost");
+
+    let operator =
+        find_operator("800725", "DB", "S-Bahn Berlin GmbH").expect("S-Bahn Berlin is missing");
+    assert_eq!(operator.display_name, "S-Bahn Berlin GmbH");
+}
+
+#[test]
+fn display_names_are_kept() {
+    let display_name = |administration_id| {
+        operator_by_administration_id(administration_id)
+            .map(|operator| operator.display_name.as_str())
+    };
+
+    assert_eq!(display_name("80"), Some("DB Fernverkehr AG"));
+    assert_eq!(display_name("82"), Some("CFL"));
+    assert_eq!(display_name("87"), Some("SNCF"));
+    assert_eq!(display_name("88"), Some("SNCB"));
+
+    let operator = operator_by_code("ME").expect("metronom is missing");
+    assert_eq!(operator.display_name, "metronom Eisenbahngesellschaft mbH");
+}
+
+#[test]
+fn operators_are_searched() {
+    let operators = search_operators("go-ahead");
+
+    assert_eq!(operators.len(), 2);
+    assert!(operators.iter().all(|operator| operator.country == "DE"));
+}
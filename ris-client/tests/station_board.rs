@@ -1,6 +1,7 @@
 use chrono::{Datelike, Duration, TimeZone, Timelike};
 use chrono_tz::Europe::Berlin;
 use dotenvy::dotenv;
+use ris_client::station_board::ExpandTimeFrame;
 use ris_client::RisClient;
 
 #[tokio::test]
@@ -13,7 +14,7 @@ async fn station_board() {
     let ris_client = RisClient::new(None, None, None, &client_id, &api_key);
 
     let result = ris_client
-        .station_board_departures("8000105", None, None)
+        .station_board_departures("8000105", None, None, ExpandTimeFrame::TimeStart)
         .await;
 
     assert!(result.is_ok());
@@ -34,7 +35,12 @@ async fn station_board_time_range() {
     let time_end = current_time + Duration::minutes(60);
 
     let result = ris_client
-        .station_board_departures("8000105", Some(time_start), Some(time_end))
+        .station_board_departures(
+            "8000105",
+            Some(time_start),
+            Some(time_end),
+            ExpandTimeFrame::TimeStart,
+        )
         .await;
 
     let result = result.expect("Failed to get Station Board");
@@ -0,0 +1,316 @@
+use chrono::{Duration, TimeZone};
+use chrono_tz::Europe::Berlin;
+use ris_client::station_board::response::StationBoardResponse;
+use ris_client::station_board::{
+    merge_station_board_pages, time_frame_chunks, ExpandTimeFrame, MAX_TIME_FRAME_MINUTES,
+};
+use ris_client::{RisBackend, RisBackends, RisClient};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+fn item(journey_id: &str, time: &str) -> String {
+    format!(
+        r#"{{"station":{{"evaNo":"8000105","name":"Frankfurt(Main)Hbf","canceled":false}},"train":{{"journeyId":"{journey_id}","lineName":"RE 60","no":4611,"category":"RE","type":"REGIONAL_TRAIN"}},"category":"RE","platform":"18","platformPredicted":"18","timePredicted":"{time}","time":"{time}","timeType":"SCHEDULE","canceled":false,"diff":0,"origin":null,"destination":null,"administration":{{"id":"800337","operatorCode":"DB","operatorName":"DB Regio AG"}}}}"#
+    )
+}
+
+fn page(time_start: &str, time_end: &str, items: &[String]) -> String {
+    format!(
+        r#"{{"isArrival":false,"evaNo":"8000105","stationName":"Frankfurt(Main)Hbf","timeStart":"{time_start}","timeEnd":"{time_end}","items":[{}]}}"#,
+        items.join(",")
+    )
+}
+
+fn parse_page(page: &str) -> StationBoardResponse {
+    serde_json::from_str(page).expect("Failed to parse station board page")
+}
+
+/// Starts a stand-in server that answers every request with the page for the requested `timeStart` (or the path it starts with) \
+/// and returns the requested paths.
+async fn stand_in_server(pages: Vec<(&'static str, String)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in server");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let mut paths = Vec::new();
+
+        for _ in 0..pages.len() {
+            let (mut stream, _) = listener.accept().await.expect("No request received");
+
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream
+                    .read(&mut buffer)
+                    .await
+                    .expect("Failed to read request");
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let path = String::from_utf8_lossy(&request)
+                .split(' ')
+                .nth(1)
+                .unwrap_or_default()
+                .to_owned();
+
+            let body = pages
+                .iter()
+                .find(|(key, _)| {
+                    path.starts_with(key) || path.contains(&urlencoding::encode(key).into_owned())
+                })
+                .map(|(_, body)| body.to_owned())
+                .unwrap_or_else(|| panic!("Unexpected request {path}"));
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream
+                .write_all(response.as_bytes())
+                .await
+                .expect("Failed to write response");
+
+            paths.push(path);
+        }
+
+        paths
+    });
+
+    (url, handle)
+}
+
+#[test]
+fn time_frames_are_chunked() {
+    let start = Berlin.with_ymd_and_hms(2023, 10, 18, 12, 0, 0).unwrap();
+
+    let chunks = time_frame_chunks(start, start + Duration::minutes(150), Duration::hours(1));
+
+    assert_eq!(
+        chunks,
+        vec![
+            (start, start + Duration::hours(1)),
+            (start + Duration::hours(1), start + Duration::hours(2)),
+            (start + Duration::hours(2), start + Duration::minutes(150)),
+        ]
+    );
+
+    assert_eq!(
+        time_frame_chunks(start, start + Duration::minutes(30), Duration::hours(1)),
+        vec![(start, start + Duration::minutes(30))]
+    );
+    assert_eq!(
+        time_frame_chunks(start, start, Duration::hours(1)),
+        vec![(start, start)]
+    );
+}
+
+#[test]
+fn pages_are_merged_without_duplicates() {
+    let first = parse_page(&page(
+        "2023-10-18T12:00:00+02:00",
+        "2023-10-18T13:00:00+02:00",
+        &[
+            item("a", "2023-10-18T12:10:00+02:00"),
+            item("b", "2023-10-18T13:00:00+02:00"),
+        ],
+    ));
+    let second = parse_page(&page(
+        "2023-10-18T13:00:00+02:00",
+        "2023-10-18T14:00:00+02:00",
+        &[
+            item("b", "2023-10-18T13:00:00+02:00"),
+            item("c", "2023-10-18T13:30:00+02:00"),
+        ],
+    ));
+
+    let merged = merge_station_board_pages(vec![first.clone(), second.clone()])
+        .expect("Merging two pages returns a page");
+
+    assert_eq!(merged.time_start, first.time_start);
+    assert_eq!(merged.time_end, second.time_end);
+    assert_eq!(
+        merged
+            .items
+            .iter()
+            .map(|item| item.train.journey_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["a", "b", "c"]
+    );
+
+    assert_eq!(merge_station_board_pages(vec![]), None);
+}
+
+#[test]
+fn services_calling_twice_are_kept() {
+    let first = parse_page(&page(
+        "2023-10-18T12:00:00+02:00",
+        "2023-10-18T13:00:00+02:00",
+        &[
+            item("loop", "2023-10-18T12:10:00+02:00"),
+            item("loop", "2023-10-18T13:00:00+02:00"),
+        ],
+    ));
+    let second = parse_page(&page(
+        "2023-10-18T13:00:00+02:00",
+        "2023-10-18T14:00:00+02:00",
+        &[
+            item("loop", "2023-10-18T13:00:00+02:00"),
+            item("loop", "2023-10-18T13:50:00+02:00"),
+        ],
+    ));
+
+    let merged =
+        merge_station_board_pages(vec![first, second]).expect("Merging two pages returns a page");
+
+    assert_eq!(
+        merged
+            .items
+            .iter()
+            .map(|item| item.time.format("%H:%M").to_string())
+            .collect::<Vec<_>>(),
+        vec!["12:10", "13:00", "13:50"]
+    );
+}
+
+#[tokio::test]
+async fn long_time_frames_are_requested_in_chunks() {
+    let start = Berlin.with_ymd_and_hms(2023, 10, 18, 12, 0, 0).unwrap();
+    let middle = start + Duration::minutes(MAX_TIME_FRAME_MINUTES);
+    let end = middle + Duration::minutes(30);
+
+    let (url, paths) = stand_in_server(vec![
+        (
+            "2023-10-18T12:00:00+02:00",
+            page(
+                "2023-10-18T11:50:00+02:00",
+                "2023-10-18T13:00:00+02:00",
+                &[
+                    item("a", "2023-10-18T12:10:00+02:00"),
+                    item("b", "2023-10-18T13:00:00+02:00"),
+                ],
+            ),
+        ),
+        (
+            "2023-10-18T13:00:00+02:00",
+            page(
+                "2023-10-18T13:00:00+02:00",
+                "2023-10-18T13:30:00+02:00",
+                &[
+                    item("b", "2023-10-18T13:00:00+02:00"),
+                    item("c", "2023-10-18T13:20:00+02:00"),
+                ],
+            ),
+        ),
+    ])
+    .await;
+
+    let zugportal = RisBackend::zugportal(Some(url));
+    let ris_client =
+        RisClient::with_backends(None, None, RisBackends::new(zugportal.clone(), zugportal));
+
+    let departures = ris_client
+        .station_board_departures(
+            "8000105",
+            Some(start),
+            Some(end),
+            ExpandTimeFrame::TimeStart,
+        )
+        .await
+        .expect("Failed to get departures from stand-in server");
+
+    assert_eq!(departures.items.len(), 3);
+    assert_eq!(
+        departures.time_start,
+        Berlin
+            .with_ymd_and_hms(2023, 10, 18, 11, 50, 0)
+            .unwrap()
+            .fixed_offset()
+    );
+
+    let paths = paths.await.unwrap();
+    assert_eq!(paths.len(), 2);
+
+    for path in paths {
+        assert!(path.starts_with("/ri/board/departure/8000105?"));
+        assert_eq!(
+            path.contains("expandTimeFrame=TIME_START"),
+            path.contains(&urlencoding::encode("2023-10-18T12:00:00+02:00").into_owned()),
+            "Only the first chunk is expanded at its start: {path}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn journeys_calling_twice_are_kept() {
+    let (url, paths) = stand_in_server(vec![
+        (
+            "/ri/board/arrival/",
+            page(
+                "2023-10-18T12:00:00+02:00",
+                "2023-10-18T13:00:00+02:00",
+                &[
+                    item("turn", "2023-10-18T12:00:00+02:00"),
+                    item("turn", "2023-10-18T12:40:00+02:00"),
+                    item("end", "2023-10-18T12:30:00+02:00"),
+                ],
+            ),
+        ),
+        (
+            "/ri/board/departure/",
+            page(
+                "2023-10-18T12:00:00+02:00",
+                "2023-10-18T13:00:00+02:00",
+                &[
+                    item("start", "2023-10-18T12:05:00+02:00"),
+                    item("turn", "2023-10-18T12:02:00+02:00"),
+                    item("turn", "2023-10-18T12:42:00+02:00"),
+                ],
+            ),
+        ),
+    ])
+    .await;
+
+    let zugportal = RisBackend::zugportal(Some(url));
+    let ris_client =
+        RisClient::with_backends(None, None, RisBackends::new(zugportal.clone(), zugportal));
+
+    let station_board = ris_client
+        .station_board("8000105", None, None, ExpandTimeFrame::None)
+        .await
+        .expect("Failed to get the station board from stand-in server");
+
+    let stops = station_board
+        .items
+        .iter()
+        .map(|item| {
+            (
+                item.journey_id.as_str(),
+                item.arrival
+                    .as_ref()
+                    .map(|arrival| arrival.time_scheduled.format("%H:%M").to_string()),
+                item.departure
+                    .as_ref()
+                    .map(|departure| departure.time_scheduled.format("%H:%M").to_string()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let time = |time: &str| Some(time.to_owned());
+    assert_eq!(
+        stops,
+        vec![
+            ("end", time("12:30"), None),
+            ("start", None, time("12:05")),
+            ("turn", time("12:00"), time("12:02")),
+            ("turn", time("12:40"), time("12:42")),
+        ]
+    );
+
+    assert_eq!(paths.await.unwrap().len(), 2);
+}
@@ -2,6 +2,7 @@ use dotenvy::dotenv;
 use ris_client::RisClient;
 
 #[tokio::test]
+#[allow(deprecated)]
 async fn station_information() {
     dotenv().ok();
 
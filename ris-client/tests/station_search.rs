@@ -2,6 +2,7 @@ use dotenvy::dotenv;
 use ris_client::RisClient;
 
 #[tokio::test]
+#[allow(deprecated)]
 async fn station_search() {
     dotenv().ok();
 
@@ -20,7 +21,8 @@ async fn station_search() {
         "first result should be leipzig hbf (eva)"
     );
     assert_eq!(
-        "Leipzig Hbf", result[0].names.de.name_long,
+        "Leipzig Hbf",
+        result[0].names.get("DE").unwrap().name_long,
         "first result should be leipzig hbf (name)"
     );
 }
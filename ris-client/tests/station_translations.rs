@@ -0,0 +1,58 @@
+use ris_client::station_information::response::StationInformationResponse;
+use ris_client::station_information::RisStationInformation;
+
+const STATION: &str = include_str!("fixtures/station_information.json");
+
+fn station_information() -> RisStationInformation {
+    let response: StationInformationResponse =
+        serde_json::from_str(STATION).expect("Failed to parse station information fixture");
+
+    response
+        .stations
+        .into_iter()
+        .next()
+        .expect("No station in fixture")
+        .into()
+}
+
+#[test]
+fn every_language_is_kept() {
+    let station = station_information();
+
+    assert_eq!(station.name_translations.len(), 3);
+    assert_eq!(
+        station.name_translations["IT"].name_long,
+        "Monaco di Baviera Centrale"
+    );
+    assert_eq!(station.metropolis_translations.len(), 2);
+}
+
+#[test]
+fn german_is_the_default() {
+    let station = station_information();
+
+    assert_eq!(station.names.name_long, "München Hbf");
+    assert_eq!(station.metropolis.as_deref(), Some("München"));
+}
+
+#[test]
+fn preferred_language_is_used() {
+    let mut station = station_information();
+
+    station.localize("en");
+
+    assert_eq!(station.names.name_long, "Munich Central Station");
+    assert_eq!(station.metropolis.as_deref(), Some("Munich"));
+}
+
+#[test]
+fn missing_language_falls_back_to_german() {
+    let mut station = station_information();
+
+    station.localize("IT");
+    assert_eq!(station.names.name_long, "Monaco di Baviera Centrale");
+    assert_eq!(station.metropolis.as_deref(), Some("München"));
+
+    station.localize("fr");
+    assert_eq!(station.names.name_long, "München Hbf");
+}
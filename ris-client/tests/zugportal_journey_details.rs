@@ -0,0 +1,110 @@
+use chrono::{TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use ris_client::journey_details::zugportal_response::ZugportalJourneyResponse;
+use ris_client::journey_details::{from_zugportal_journey, RisJourneyDetails};
+use ris_client::station_board::ExpandTimeFrame;
+use ris_client::RisClient;
+
+const JOURNEY: &str = include_str!("fixtures/zugportal_journey.json");
+
+fn journey_details() -> RisJourneyDetails {
+    let response: ZugportalJourneyResponse =
+        serde_json::from_str(JOURNEY).expect("Failed to parse journey fixture");
+
+    from_zugportal_journey(response).expect("Journey has no stops")
+}
+
+#[tokio::test]
+async fn zugportal_journey_details() {
+    let ris_client = RisClient::new(None, None, None, "", "");
+
+    let current = Berlin.from_utc_datetime(&Utc::now().naive_utc());
+
+    let station_board = ris_client
+        .station_board_departures(
+            "8000105",
+            Some(current),
+            Some(current + chrono::Duration::hours(1)),
+            ExpandTimeFrame::TimeStart,
+        )
+        .await
+        .expect("Failed to get station board");
+
+    let first = station_board
+        .items
+        .into_iter()
+        .find(|item| item.train.category == "ICE")
+        .expect("No ICE in departure board of Frankfurt. Is it night?");
+
+    let journey_details = ris_client
+        .journey_details(&first.train.journey_id)
+        .await
+        .unwrap_or_else(|e| {
+            panic!(
+                "Failed to get journey details for train: {:#?} \n Error: {:#?}",
+                first.train, e
+            )
+        });
+
+    let stop = journey_details
+        .stops
+        .into_iter()
+        .find(|stop| stop.stop_id == "8000105")
+        .expect("Failed to get right station");
+
+    assert_eq!(first.train.category, stop.transport.category);
+}
+
+#[test]
+fn zugportal_journey_is_transformed() {
+    let journey_details = journey_details();
+
+    assert_eq!(journey_details.origin_name, "Hamburg-Altona");
+    assert_eq!(journey_details.destination_id, "8000261");
+    assert_eq!(journey_details.journey_type, "HIGH_SPEED_TRAIN");
+    assert!(!journey_details.cancelled);
+    assert_eq!(journey_details.stops.len(), 4);
+
+    let frankfurt = &journey_details.stops[1];
+
+    assert_eq!(frankfurt.scheduled_platform.as_deref(), Some("7"));
+    assert_eq!(frankfurt.real_platform.as_deref(), Some("9"));
+    assert_eq!(frankfurt.transport.category, "ICE");
+    assert_eq!(frankfurt.transport.number, 1011);
+    assert_eq!(frankfurt.transport.line, None);
+    assert_eq!(frankfurt.administration.name, "DB Fernverkehr AG");
+    assert_eq!(frankfurt.messages.len(), 1);
+
+    let arrival = frankfurt
+        .arrival
+        .as_ref()
+        .expect("Frankfurt has an arrival");
+    assert_eq!(
+        arrival.realtime,
+        Some(
+            Berlin
+                .with_ymd_and_hms(2023, 10, 18, 13, 7, 0)
+                .unwrap()
+                .fixed_offset()
+        )
+    );
+    assert!(!arrival.cancelled);
+}
+
+#[test]
+fn cancelled_zugportal_stops_are_kept() {
+    let journey_details = journey_details();
+
+    let augsburg = &journey_details.stops[2];
+
+    assert_eq!(augsburg.scheduled_platform, None);
+    assert!(augsburg
+        .arrival
+        .as_ref()
+        .is_some_and(|arrival| arrival.cancelled && arrival.realtime.is_none()));
+    assert!(augsburg
+        .departure
+        .as_ref()
+        .is_some_and(|departure| departure.cancelled));
+    assert!(journey_details.stops[3].departure.is_none());
+}
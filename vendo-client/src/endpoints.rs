@@ -1,3 +1,5 @@
+pub mod connection_search;
 pub mod journey_details;
 pub mod location_search;
+pub mod nearby;
 pub mod station_board;
@@ -0,0 +1,213 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeZone};
+use chrono_tz::{Europe::Berlin, Tz};
+use reqwest::header::{HeaderValue, ACCEPT, CONTENT_TYPE};
+use serde::Deserialize;
+
+use crate::connection_search::response::ConnectionSearchResponse;
+use crate::shared::PagingDirection;
+use crate::station_board::VendoTransportType;
+use crate::{VendoClient, VendoError, VendoOrRequestError};
+
+mod request;
+pub mod response;
+mod transformed;
+
+pub use request::*;
+pub use transformed::*;
+
+const VENDO_CONNECTION_SEARCH_HEADER: &str = "application/x.db.vendo.mob.verbindungssuche.v8+json";
+
+/// The time a connection should depart or arrive at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VendoConnectionTime {
+    Departure(DateTime<Tz>),
+    Arrival(DateTime<Tz>),
+    /// Continue a previous search with the [`cursor`](VendoConnectionSearch::cursor) of its result
+    Cursor {
+        cursor: String,
+        direction: PagingDirection,
+    },
+}
+
+/// The contexts Vendo returns to search for earlier or later connections.
+///
+/// Formatted as both url encoded contexts separated by a comma, a missing context is left empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionSearchCursor {
+    pub earlier: Option<String>,
+    pub later: Option<String>,
+}
+
+impl Display for ConnectionSearchCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let encode = |context: &Option<String>| {
+            context
+                .as_deref()
+                .map(|context| urlencoding::encode(context).into_owned())
+                .unwrap_or_default()
+        };
+
+        write!(f, "{},{}", encode(&self.earlier), encode(&self.later))
+    }
+}
+
+impl FromStr for ConnectionSearchCursor {
+    type Err = VendoOrRequestError;
+
+    fn from_str(cursor: &str) -> Result<Self, Self::Err> {
+        let decode = |context: &str| {
+            if context.is_empty() {
+                return Ok(None);
+            }
+
+            urlencoding::decode(context)
+                .map(|context| Some(context.into_owned()))
+                .map_err(|_| VendoOrRequestError::InvalidCursor {
+                    cursor: cursor.to_string(),
+                })
+        };
+
+        let (earlier, later) =
+            cursor
+                .split_once(',')
+                .ok_or_else(|| VendoOrRequestError::InvalidCursor {
+                    cursor: cursor.to_string(),
+                })?;
+
+        Ok(Self {
+            earlier: decode(earlier)?,
+            later: decode(later)?,
+        })
+    }
+}
+
+impl VendoClient {
+    /// Search for connections from one location to another.
+    ///
+    /// All locations should be given as the eva number (e.G. `8000105`) \
+    /// or Location ID (e.G. `A=1@O=Frankfurt(Main)Hbf@X=8663785@Y=50107149@U=80@L=8000105@B=1@p=1673463547@`).
+    /// If no time is provided, connections departing now are searched.
+    /// To get earlier or later connections, pass the cursor of a previous result with the same parameters.
+    /// Transport types can be provided to filter the results, they apply to the via locations as well.
+    /// If no maximum of transfers is provided, Vendo decides how many transfers are acceptable.
+    pub async fn connection_search(
+        &self,
+        from: &str,
+        to: &str,
+        via: Option<Vec<String>>,
+        time: Option<VendoConnectionTime>,
+        transport_types: Option<Vec<VendoTransportType>>,
+        max_transfers: Option<u32>,
+    ) -> Result<VendoConnectionSearch, VendoOrRequestError> {
+        let now = || Berlin.from_utc_datetime(&chrono::Utc::now().naive_utc());
+
+        let (date, time_type, context) = match time
+            .unwrap_or_else(|| VendoConnectionTime::Departure(now()))
+        {
+            VendoConnectionTime::Departure(date) => {
+                (date, ConnectionSearchTimeType::Departure, None)
+            }
+            VendoConnectionTime::Arrival(date) => (date, ConnectionSearchTimeType::Arrival, None),
+            VendoConnectionTime::Cursor { cursor, direction } => {
+                let parsed: ConnectionSearchCursor = cursor.parse()?;
+
+                let context = match direction {
+                    PagingDirection::Earlier => parsed.earlier,
+                    PagingDirection::Later => parsed.later,
+                }
+                .ok_or(VendoOrRequestError::InvalidCursor { cursor })?;
+
+                (now(), ConnectionSearchTimeType::Departure, Some(context))
+            }
+        };
+
+        let _permit = self.semaphore.acquire().await;
+
+        let transport_types = transport_types.unwrap_or_else(|| VendoTransportType::ALL.to_vec());
+
+        let body = VendoConnectionSearchRequest {
+            autonomous_reservation: false,
+            entry_types: vec![String::from("STANDARD")],
+            class: String::from("KLASSE_2"),
+            traveller_profile: ConnectionSearchTravellerProfile {
+                travellers: vec![ConnectionSearchTraveller {
+                    discounts: vec![String::from("KEINE_ERMAESSIGUNG KLASSENLOS")],
+                    traveller_type: String::from("ERWACHSENER"),
+                    ages: vec![],
+                    count: 1,
+                }],
+            },
+            reservation_contingents: false,
+            search_parameters: ConnectionSearchParameters {
+                outward: ConnectionSearchWish {
+                    origin: location_id(from),
+                    destination: location_id(to),
+                    via: via
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|via| ConnectionSearchVia {
+                            location_id: location_id(via),
+                            transport_types: transport_types.clone(),
+                        })
+                        .collect(),
+                    time: ConnectionSearchTime {
+                        date: date.to_rfc3339(),
+                        time_type,
+                    },
+                    transport_types,
+                    max_transfers,
+                    context,
+                    economic: false,
+                },
+            },
+        };
+
+        let mut request = self
+            .client
+            .post(format!("{}/mob/angebote/fahrplan", self.base_url))
+            .json(&body)
+            .build()?;
+
+        let headers = request.headers_mut();
+
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(VENDO_CONNECTION_SEARCH_HEADER),
+        );
+        headers.insert(
+            ACCEPT,
+            HeaderValue::from_static(VENDO_CONNECTION_SEARCH_HEADER),
+        );
+
+        headers.insert("x-correlation-id", HeaderValue::from_static("railboard"));
+
+        let response: VendoConnectionSearchResponse =
+            self.client.execute(request).await?.json().await?;
+
+        match response {
+            VendoConnectionSearchResponse::VendoResponse(response) => Ok((*response).into()),
+            VendoConnectionSearchResponse::VendoError(error) => {
+                Err(VendoOrRequestError::VendoError(error))
+            }
+        }
+    }
+}
+
+/// Vendo only accepts location ids for connections, so eva numbers are turned into a minimal location id.
+fn location_id(location: &str) -> String {
+    if !location.is_empty() && location.chars().all(|c| c.is_ascii_digit()) {
+        format!("A=1@L={location}@")
+    } else {
+        location.to_string()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum VendoConnectionSearchResponse {
+    VendoResponse(Box<ConnectionSearchResponse>),
+    VendoError(VendoError),
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::station_board::VendoTransportType;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VendoConnectionSearchRequest {
+    #[serde(rename = "autonomeReservierung")]
+    pub autonomous_reservation: bool,
+    #[serde(rename = "einstiegsTypList")]
+    pub entry_types: Vec<String>,
+    #[serde(rename = "klasse")]
+    pub class: String,
+    #[serde(rename = "reisendenProfil")]
+    pub traveller_profile: ConnectionSearchTravellerProfile,
+    #[serde(rename = "reservierungsKontingenteVorhanden")]
+    pub reservation_contingents: bool,
+    #[serde(rename = "suchParameter")]
+    pub search_parameters: ConnectionSearchParameters,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchTravellerProfile {
+    #[serde(rename = "reisende")]
+    pub travellers: Vec<ConnectionSearchTraveller>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchTraveller {
+    #[serde(rename = "ermaessigungen")]
+    pub discounts: Vec<String>,
+    #[serde(rename = "reisendenTyp")]
+    pub traveller_type: String,
+    #[serde(rename = "alter")]
+    pub ages: Vec<u32>,
+    #[serde(rename = "anzahl")]
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchParameters {
+    #[serde(rename = "reisewunschHin")]
+    pub outward: ConnectionSearchWish,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchWish {
+    #[serde(rename = "abgangsLocationId")]
+    pub origin: String,
+    #[serde(rename = "zielLocationId")]
+    pub destination: String,
+    #[serde(
+        rename = "viaLocations",
+        skip_serializing_if = "Vec::is_empty",
+        default
+    )]
+    pub via: Vec<ConnectionSearchVia>,
+    #[serde(rename = "zeitWunsch")]
+    pub time: ConnectionSearchTime,
+    #[serde(rename = "verkehrsmittel")]
+    pub transport_types: Vec<VendoTransportType>,
+    #[serde(rename = "maxUmstiege", skip_serializing_if = "Option::is_none")]
+    pub max_transfers: Option<u32>,
+    /// The context Vendo returned to search for earlier or later connections
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    pub economic: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchVia {
+    #[serde(rename = "locationId")]
+    pub location_id: String,
+    #[serde(rename = "verkehrsmittel")]
+    pub transport_types: Vec<VendoTransportType>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchTime {
+    #[serde(rename = "reiseDatum")]
+    pub date: String,
+    #[serde(rename = "zeitPunktArt")]
+    pub time_type: ConnectionSearchTimeType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub enum ConnectionSearchTimeType {
+    #[serde(rename = "ABFAHRT")]
+    Departure,
+    #[serde(rename = "ANKUNFT")]
+    Arrival,
+}
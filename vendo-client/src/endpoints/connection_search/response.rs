@@ -0,0 +1,125 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+use crate::journey_details::response::{
+    JourneyDetailsAttribute, JourneyDetailsHimNotice, JourneyDetailsNotice,
+    JourneyDetailsStopPosition,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchResponse {
+    #[serde(rename = "verbindungen")]
+    pub connections: Vec<ConnectionSearchElement>,
+    #[serde(rename = "frueherContext")]
+    pub earlier_context: Option<String>,
+    #[serde(rename = "spaeterContext")]
+    pub later_context: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchElement {
+    #[serde(rename = "verbindung")]
+    pub connection: ConnectionSearchConnection,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchConnection {
+    #[serde(rename = "kontext")]
+    pub context: String,
+    #[serde(rename = "verbindungsAbschnitte")]
+    pub sections: Vec<ConnectionSearchSection>,
+    #[serde(rename = "umstiegsAnzahl", default)]
+    pub transfer_count: u32,
+    /// In seconds
+    #[serde(rename = "reiseDauer")]
+    pub duration: Option<i64>,
+    #[serde(rename = "echtzeitNotizen", default)]
+    pub notes: Vec<JourneyDetailsNotice>,
+    #[serde(rename = "himNotizen", default)]
+    pub him_notices: Vec<JourneyDetailsHimNotice>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchSection {
+    #[serde(rename = "typ")]
+    pub section_type: ConnectionSearchSectionType,
+    #[serde(rename = "abgangsOrt")]
+    pub origin: ConnectionSearchLocation,
+    #[serde(rename = "ankunftsOrt")]
+    pub destination: ConnectionSearchLocation,
+    #[serde(rename = "abgangsDatum")]
+    pub departure: DateTime<FixedOffset>,
+    #[serde(rename = "ezAbgangsDatum")]
+    pub realtime_departure: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "ankunftsDatum")]
+    pub arrival: DateTime<FixedOffset>,
+    #[serde(rename = "ezAnkunftsDatum")]
+    pub realtime_arrival: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "zuglaufId")]
+    pub journey_id: Option<String>,
+    #[serde(rename = "kurztext")]
+    pub short_name: Option<String>,
+    #[serde(rename = "mitteltext")]
+    pub name: Option<String>,
+    #[serde(rename = "langtext")]
+    pub long_name: Option<String>,
+    #[serde(rename = "produktGattung")]
+    pub product_type: Option<String>,
+    #[serde(rename = "richtung")]
+    pub direction: Option<String>,
+    #[serde(rename = "verkehrsmittelNummer")]
+    pub transport_number: Option<String>,
+    /// In meters, only set for walks
+    #[serde(rename = "distanz")]
+    pub distance: Option<u32>,
+    #[serde(rename = "halte", default)]
+    pub stops: Vec<ConnectionSearchStop>,
+    #[serde(rename = "echtzeitNotizen", default)]
+    pub notes: Vec<JourneyDetailsNotice>,
+    #[serde(rename = "himNotizen", default)]
+    pub him_notices: Vec<JourneyDetailsHimNotice>,
+    #[serde(rename = "attributNotizen", default)]
+    pub attributes: Vec<JourneyDetailsAttribute>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionSearchSectionType {
+    #[serde(rename = "FAHRZEUG")]
+    Transport,
+    #[serde(rename = "FUSSWEG")]
+    Walk,
+    #[serde(rename = "TRANSFER")]
+    Transfer,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchLocation {
+    pub name: String,
+    #[serde(rename = "locationId")]
+    pub location_id: String,
+    #[serde(rename = "evaNr")]
+    pub eva: Option<String>,
+    pub position: Option<JourneyDetailsStopPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectionSearchStop {
+    #[serde(rename = "ort")]
+    pub stop_details: ConnectionSearchLocation,
+    #[serde(rename = "ankunftsDatum")]
+    pub arrival: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "ezAnkunftsDatum")]
+    pub realtime_arrival: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "abgangsDatum")]
+    pub departure: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "ezAbgangsDatum")]
+    pub realtime_departure: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "gleis")]
+    pub platform: Option<String>,
+    #[serde(rename = "ezGleis")]
+    pub realtime_platform: Option<String>,
+    #[serde(rename = "echtzeitNotizen", default)]
+    pub notes: Vec<JourneyDetailsNotice>,
+}
@@ -0,0 +1,308 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::connection_search::response::{
+    ConnectionSearchConnection, ConnectionSearchLocation, ConnectionSearchResponse,
+    ConnectionSearchSection, ConnectionSearchSectionType, ConnectionSearchStop,
+};
+use crate::connection_search::ConnectionSearchCursor;
+use crate::journey_details::PolylinePosition;
+use crate::shared::{Attribute, HimNotice, Time};
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoConnectionSearch {
+    /// Pass this as [`VendoConnectionTime::Cursor`](crate::connection_search::VendoConnectionTime::Cursor) to get earlier or later connections, \
+    /// not set if Vendo returned no contexts to continue with
+    #[schema(nullable)]
+    pub cursor: Option<String>,
+    pub connections: Vec<VendoConnection>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoConnection {
+    /// The context Vendo uses to identify the connection
+    pub context: String,
+    pub departure: Time,
+    pub arrival: Time,
+    /// The scheduled duration of the whole connection in minutes
+    pub duration: i64,
+    pub transfer_count: u32,
+    pub sections: Vec<VendoConnectionSection>,
+    /// The transfers between two sections in a vehicle, walks in between are part of the transfer
+    pub transfers: Vec<VendoTransfer>,
+    pub notes: Vec<String>,
+    pub him_notices: Vec<HimNotice>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+pub enum VendoConnectionSectionType {
+    /// A section in a train, bus or any other vehicle
+    Transport,
+    Walk,
+    /// A transfer within a station that is not a walk
+    Transfer,
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoConnectionSection {
+    pub section_type: VendoConnectionSectionType,
+    #[schema(nullable)]
+    pub journey_id: Option<String>,
+    #[schema(nullable)]
+    pub short_name: Option<String>,
+    #[schema(nullable)]
+    pub name: Option<String>,
+    #[schema(nullable)]
+    pub long_name: Option<String>,
+    #[schema(nullable)]
+    pub product_type: Option<String>,
+    #[schema(nullable)]
+    pub direction: Option<String>,
+    #[schema(nullable)]
+    pub transport_number: Option<String>,
+    pub origin: VendoConnectionLocation,
+    pub destination: VendoConnectionLocation,
+    pub departure: Time,
+    pub arrival: Time,
+    #[schema(nullable)]
+    pub departure_platform: Option<String>,
+    #[schema(nullable)]
+    pub realtime_departure_platform: Option<String>,
+    #[schema(nullable)]
+    pub arrival_platform: Option<String>,
+    #[schema(nullable)]
+    pub realtime_arrival_platform: Option<String>,
+    /// In meters, only set for walks
+    #[schema(nullable)]
+    pub distance: Option<u32>,
+    /// All stops of the section including its origin and destination
+    pub stops: Vec<VendoConnectionStop>,
+    pub notes: Vec<String>,
+    pub him_notices: Vec<HimNotice>,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoConnectionLocation {
+    pub name: String,
+    #[schema(nullable)]
+    pub eva: Option<String>,
+    pub location_id: String,
+    #[schema(nullable)]
+    pub position: Option<PolylinePosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoConnectionStop {
+    pub name: String,
+    #[schema(nullable)]
+    pub eva: Option<String>,
+    pub location_id: String,
+    #[schema(nullable)]
+    pub arrival: Option<Time>,
+    #[schema(nullable)]
+    pub departure: Option<Time>,
+    #[schema(nullable)]
+    pub platform: Option<String>,
+    #[schema(nullable)]
+    pub realtime_platform: Option<String>,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoTransfer {
+    /// Where the previous vehicle arrives
+    pub from: VendoConnectionLocation,
+    /// Where the next vehicle departs, differs from `from` if the transfer includes a walk
+    pub to: VendoConnectionLocation,
+    #[schema(nullable)]
+    pub from_platform: Option<String>,
+    #[schema(nullable)]
+    pub to_platform: Option<String>,
+    /// The scheduled time between the arrival and the next departure in minutes
+    pub scheduled_duration: i64,
+    /// The time between the arrival and the next departure in minutes including delays, \
+    /// only set if there is realtime data for either of them
+    #[schema(nullable)]
+    pub realtime_duration: Option<i64>,
+}
+
+impl From<ConnectionSearchResponse> for VendoConnectionSearch {
+    fn from(value: ConnectionSearchResponse) -> Self {
+        let cursor =
+            (value.earlier_context.is_some() || value.later_context.is_some()).then(|| {
+                ConnectionSearchCursor {
+                    earlier: value.earlier_context,
+                    later: value.later_context,
+                }
+                .to_string()
+            });
+
+        Self {
+            cursor,
+            connections: value
+                .connections
+                .into_iter()
+                .filter_map(|element| VendoConnection::from_response(element.connection))
+                .collect(),
+        }
+    }
+}
+
+impl VendoConnection {
+    /// Returns `None` for connections without any section.
+    fn from_response(value: ConnectionSearchConnection) -> Option<Self> {
+        let sections: Vec<VendoConnectionSection> =
+            value.sections.into_iter().map(Into::into).collect();
+
+        let departure = sections.first()?.departure.clone();
+        let arrival = sections.last()?.arrival.clone();
+
+        Some(Self {
+            context: value.context,
+            duration: value
+                .duration
+                .map(|duration| duration / 60)
+                .unwrap_or_else(|| (arrival.scheduled - departure.scheduled).num_minutes()),
+            departure,
+            arrival,
+            transfer_count: value.transfer_count,
+            transfers: transfers(&sections),
+            sections,
+            notes: value.notes.into_iter().map(|note| note.text).collect(),
+            him_notices: value.him_notices.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+fn transfers(sections: &[VendoConnectionSection]) -> Vec<VendoTransfer> {
+    let transports: Vec<&VendoConnectionSection> = sections
+        .iter()
+        .filter(|section| section.section_type == VendoConnectionSectionType::Transport)
+        .collect();
+
+    transports
+        .windows(2)
+        .map(|pair| {
+            let (previous, next) = (pair[0], pair[1]);
+
+            let realtime_duration = (previous.arrival.realtime.is_some()
+                || next.departure.realtime.is_some())
+            .then(|| {
+                let arrival = previous
+                    .arrival
+                    .realtime
+                    .unwrap_or(previous.arrival.scheduled);
+                let departure = next.departure.realtime.unwrap_or(next.departure.scheduled);
+
+                (departure - arrival).num_minutes()
+            });
+
+            VendoTransfer {
+                from: previous.destination.clone(),
+                to: next.origin.clone(),
+                from_platform: previous
+                    .realtime_arrival_platform
+                    .clone()
+                    .or_else(|| previous.arrival_platform.clone()),
+                to_platform: next
+                    .realtime_departure_platform
+                    .clone()
+                    .or_else(|| next.departure_platform.clone()),
+                scheduled_duration: (next.departure.scheduled - previous.arrival.scheduled)
+                    .num_minutes(),
+                realtime_duration,
+            }
+        })
+        .collect()
+}
+
+impl From<ConnectionSearchSection> for VendoConnectionSection {
+    fn from(value: ConnectionSearchSection) -> Self {
+        let first = value.stops.first();
+        let last = value.stops.last();
+
+        Self {
+            section_type: value.section_type.into(),
+            departure_platform: first.and_then(|stop| stop.platform.clone()),
+            realtime_departure_platform: first.and_then(|stop| stop.realtime_platform.clone()),
+            arrival_platform: last.and_then(|stop| stop.platform.clone()),
+            realtime_arrival_platform: last.and_then(|stop| stop.realtime_platform.clone()),
+            journey_id: value.journey_id,
+            short_name: value.short_name,
+            name: value.name,
+            long_name: value.long_name,
+            product_type: value.product_type,
+            direction: value.direction,
+            transport_number: value.transport_number,
+            origin: value.origin.into(),
+            destination: value.destination.into(),
+            departure: Time {
+                scheduled: value.departure,
+                realtime: value.realtime_departure,
+            },
+            arrival: Time {
+                scheduled: value.arrival,
+                realtime: value.realtime_arrival,
+            },
+            distance: value.distance,
+            stops: value.stops.into_iter().map(Into::into).collect(),
+            notes: value.notes.into_iter().map(|note| note.text).collect(),
+            him_notices: value.him_notices.into_iter().map(Into::into).collect(),
+            attributes: value.attributes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ConnectionSearchSectionType> for VendoConnectionSectionType {
+    fn from(value: ConnectionSearchSectionType) -> Self {
+        match value {
+            ConnectionSearchSectionType::Transport => VendoConnectionSectionType::Transport,
+            ConnectionSearchSectionType::Walk => VendoConnectionSectionType::Walk,
+            ConnectionSearchSectionType::Transfer => VendoConnectionSectionType::Transfer,
+            ConnectionSearchSectionType::Other => VendoConnectionSectionType::Other,
+        }
+    }
+}
+
+impl From<ConnectionSearchLocation> for VendoConnectionLocation {
+    fn from(value: ConnectionSearchLocation) -> Self {
+        Self {
+            name: value.name,
+            eva: value.eva,
+            location_id: value.location_id,
+            position: value.position.map(|position| PolylinePosition {
+                longitude: position.longitude,
+                latitude: position.latitude,
+            }),
+        }
+    }
+}
+
+impl From<ConnectionSearchStop> for VendoConnectionStop {
+    fn from(value: ConnectionSearchStop) -> Self {
+        Self {
+            name: value.stop_details.name,
+            eva: value.stop_details.eva,
+            location_id: value.stop_details.location_id,
+            arrival: value.arrival.map(|arrival| Time {
+                scheduled: arrival,
+                realtime: value.realtime_arrival,
+            }),
+            departure: value.departure.map(|departure| Time {
+                scheduled: departure,
+                realtime: value.realtime_departure,
+            }),
+            platform: value.platform,
+            realtime_platform: value.realtime_platform,
+            notes: value.notes.into_iter().map(|note| note.text).collect(),
+        }
+    }
+}
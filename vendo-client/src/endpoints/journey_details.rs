@@ -5,7 +5,7 @@ use urlencoding::encode;
 pub use transformed::*;
 
 use crate::journey_details::response::JourneyDetailsResponse;
-use crate::shared::Time;
+use crate::shared::{Time, VendoDemand};
 use crate::{VendoClient, VendoError, VendoOrRequestError};
 
 pub mod response;
@@ -43,6 +43,45 @@ impl VendoClient {
 
         match response {
             VendoJourneyDetailsResponse::VendoResponse(response) => {
+                let stops: Vec<VendoStop> = response
+                    .stops
+                    .into_iter()
+                    .map(|stop| VendoStop {
+                        name: stop.stop_details.name,
+                        eva: stop.stop_details.eva,
+                        position: PolylinePosition {
+                            longitude: stop.stop_details.position.longitude,
+                            latitude: stop.stop_details.position.latitude,
+                        },
+                        arrival: stop.arrival.map(|arrival| Time {
+                            scheduled: arrival,
+                            realtime: stop.realtime_arrival,
+                        }),
+                        departure: stop.departure.map(|departure| Time {
+                            scheduled: departure,
+                            realtime: stop.realtime_departure,
+                        }),
+                        platform: stop.platform,
+                        realtime_platform: stop.realtime_platform,
+                        notes: stop.notes.into_iter().map(|note| note.text).collect(),
+                        him_notices: stop
+                            .him_notices
+                            .into_iter()
+                            .map(|from| from.into())
+                            .collect(),
+                        attributes: stop
+                            .attributes
+                            .into_iter()
+                            .map(|from| from.into())
+                            .collect(),
+                        service_note: stop.service_note.map(|service| service.into()),
+                        demand: stop.demand.into_iter().map(|from| from.into()).collect(),
+                    })
+                    .collect();
+
+                let max_demand =
+                    VendoDemand::max_per_class(stops.iter().flat_map(|stop| &stop.demand));
+
                 let mapped = VendoJourneyDetails {
                     short_name: response.short_name,
                     name: response.name,
@@ -51,40 +90,7 @@ impl VendoClient {
 
                     journey_id: id.to_string(),
 
-                    stops: response
-                        .stops
-                        .into_iter()
-                        .map(|stop| VendoStop {
-                            name: stop.stop_details.name,
-                            eva: stop.stop_details.eva,
-                            position: PolylinePosition {
-                                longitude: stop.stop_details.position.longitude,
-                                latitude: stop.stop_details.position.latitude,
-                            },
-                            arrival: stop.arrival.map(|arrival| Time {
-                                scheduled: arrival,
-                                realtime: stop.realtime_arrival,
-                            }),
-                            departure: stop.departure.map(|departure| Time {
-                                scheduled: departure,
-                                realtime: stop.realtime_departure,
-                            }),
-                            platform: stop.platform,
-                            realtime_platform: stop.realtime_platform,
-                            notes: stop.notes.into_iter().map(|note| note.text).collect(),
-                            him_notices: stop
-                                .him_notices
-                                .into_iter()
-                                .map(|from| from.into())
-                                .collect(),
-                            attributes: stop
-                                .attributes
-                                .into_iter()
-                                .map(|from| from.into())
-                                .collect(),
-                            service_note: stop.service_note.map(|service| service.into()),
-                        })
-                        .collect(),
+                    stops,
 
                     transport_number: response.transport_number,
                     product_type: response.product_type,
@@ -121,6 +127,8 @@ impl VendoClient {
                             })
                             .unwrap_or_default()
                     }),
+
+                    max_demand,
                 };
 
                 Ok(mapped)
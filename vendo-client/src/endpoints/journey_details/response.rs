@@ -89,7 +89,7 @@ pub struct JourneyDetailsStop {
     pub demand: Vec<JourneyDetailsStopDemand>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JourneyDetailsStopDemand {
     #[serde(rename = "klasse")]
     pub class: JourneyDetailsStopDemandClass,
@@ -99,7 +99,7 @@ pub struct JourneyDetailsStopDemand {
     pub text: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum JourneyDetailsStopDemandClass {
     #[serde(rename = "KLASSE_1")]
     Class1,
@@ -1,5 +1,8 @@
-use crate::journey_details::response::{JourneyDetailsAttribute, JourneyDetailsHimNotice};
-use crate::shared::{Attribute, HimNotice, Time};
+use crate::journey_details::response::{
+    JourneyDetailsAttribute, JourneyDetailsHimNotice, JourneyDetailsStopDemand,
+    JourneyDetailsStopDemandClass,
+};
+use crate::shared::{Attribute, HimNotice, Time, VendoDemand, VendoDemandClass};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -28,6 +31,9 @@ pub struct VendoJourneyDetails {
 
     #[schema(nullable)]
     pub polyline: Option<Vec<PolylinePosition>>,
+
+    /// The highest expected occupancy of any stop for every class
+    pub max_demand: Vec<VendoDemand>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
@@ -64,6 +70,8 @@ pub struct VendoStop {
     pub attributes: Vec<Attribute>,
     #[schema(nullable)]
     pub service_note: Option<Attribute>,
+    /// The expected occupancy after the stop for every class Vendo has a forecast for
+    pub demand: Vec<VendoDemand>,
 }
 
 impl From<JourneyDetailsHimNotice> for HimNotice {
@@ -84,3 +92,22 @@ impl From<JourneyDetailsAttribute> for Attribute {
         }
     }
 }
+
+impl From<JourneyDetailsStopDemand> for VendoDemand {
+    fn from(demand: JourneyDetailsStopDemand) -> Self {
+        VendoDemand {
+            class: demand.class.into(),
+            level: demand.demand_level.into(),
+            text: demand.text,
+        }
+    }
+}
+
+impl From<JourneyDetailsStopDemandClass> for VendoDemandClass {
+    fn from(class: JourneyDetailsStopDemandClass) -> Self {
+        match class {
+            JourneyDetailsStopDemandClass::Class1 => VendoDemandClass::FirstClass,
+            JourneyDetailsStopDemandClass::Class2 => VendoDemandClass::SecondClass,
+        }
+    }
+}
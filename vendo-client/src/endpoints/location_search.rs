@@ -10,10 +10,12 @@ const VENDO_LOCATION_SEARCH_HEADER: &str = "application/x.db.vendo.mob.location.
 
 impl VendoClient {
     /// Search for a location (e.G. Station).
+    ///
+    /// If no location types are provided, all location types are being returned.
     pub async fn location_search(
         &self,
         query: String,
-        location_types: Option<Vec<String>>,
+        location_types: Option<Vec<VendoLocationType>>,
     ) -> Result<Vec<VendoLocationSearchResult>, VendoOrRequestError> {
         let _permit = self.semaphore.acquire().await;
 
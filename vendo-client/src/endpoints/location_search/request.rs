@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use super::VendoLocationType;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LocationSearchRequest {
     pub search_term: String,
-    pub location_types: Vec<String>,
+    pub location_types: Vec<VendoLocationType>,
 }
@@ -8,7 +8,7 @@ pub struct VendoLocationSearchResult {
     #[schema(nullable)]
     pub eva_nr: Option<String>,
     pub location_id: String,
-    pub location_type: String,
+    pub location_type: VendoLocationType,
     pub name: String,
     #[serde(default)]
     pub products: Vec<String>,
@@ -16,8 +16,21 @@ pub struct VendoLocationSearchResult {
     pub weight: Option<i32>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
 pub struct VendoLocationSearchCoordinates {
     pub latitude: f64,
     pub longitude: f64,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, ToSchema)]
+pub enum VendoLocationType {
+    #[serde(rename = "ST")]
+    Station,
+    #[serde(rename = "ADR")]
+    Address,
+    #[serde(rename = "POI")]
+    PointOfInterest,
+    /// A location type this client does not know yet
+    #[serde(other)]
+    Unknown,
+}
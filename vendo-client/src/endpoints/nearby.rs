@@ -0,0 +1,82 @@
+use reqwest::header::{HeaderValue, ACCEPT, CONTENT_TYPE};
+use serde::Deserialize;
+
+use crate::location_search::{
+    VendoLocationSearchCoordinates, VendoLocationSearchResult, VendoLocationType,
+};
+use crate::{VendoClient, VendoError, VendoOrRequestError};
+
+mod request;
+mod transformed;
+
+pub use transformed::*;
+
+const VENDO_NEARBY_HEADER: &str = "application/x.db.vendo.mob.location.v3+json";
+
+impl VendoClient {
+    /// Search for locations (e.G. Stations) around coordinates.
+    ///
+    /// The radius is in meters and defaults to 1000, at most 10 locations are returned if no maximum is provided.
+    /// If no location types are provided, all location types are being returned.
+    /// The locations are sorted by their distance to the coordinates.
+    pub async fn nearby(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        radius: Option<u32>,
+        max_results: Option<u32>,
+        location_types: Option<Vec<VendoLocationType>>,
+    ) -> Result<Vec<VendoNearbyLocation>, VendoOrRequestError> {
+        let _permit = self.semaphore.acquire().await;
+
+        let radius = radius.unwrap_or(1000);
+        let max_results = max_results.unwrap_or(10);
+
+        let coordinates = VendoLocationSearchCoordinates {
+            latitude,
+            longitude,
+        };
+
+        let request = request::NearbyRequest {
+            area: request::NearbyArea {
+                coordinates: coordinates.clone(),
+                radius,
+            },
+            max_results,
+            location_types: location_types.unwrap_or_default(),
+            products: vec![String::from("ALL")],
+        };
+
+        let mut request = self
+            .client
+            .post(format!("{}/mob/location/nearby", self.base_url))
+            .json(&request)
+            .build()?;
+
+        let headers = request.headers_mut();
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(VENDO_NEARBY_HEADER));
+        headers.insert(ACCEPT, HeaderValue::from_static(VENDO_NEARBY_HEADER));
+
+        headers.insert("x-correlation-id", HeaderValue::from_static("railboard"));
+
+        let response: VendoNearbyResponse = self.client.execute(request).await?.json().await?;
+
+        match response {
+            VendoNearbyResponse::VendoResponse(response) => Ok(nearby_locations(
+                &coordinates,
+                response,
+                radius,
+                max_results as usize,
+            )),
+            VendoNearbyResponse::VendoError(error) => Err(VendoOrRequestError::VendoError(error)),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum VendoNearbyResponse {
+    VendoResponse(Vec<VendoLocationSearchResult>),
+    VendoError(VendoError),
+}
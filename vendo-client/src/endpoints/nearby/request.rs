@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::location_search::{VendoLocationSearchCoordinates, VendoLocationType};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NearbyRequest {
+    pub area: NearbyArea,
+    pub max_results: u32,
+    pub location_types: Vec<VendoLocationType>,
+    pub products: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NearbyArea {
+    pub coordinates: VendoLocationSearchCoordinates,
+    /// In meters
+    pub radius: u32,
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::location_search::{VendoLocationSearchCoordinates, VendoLocationSearchResult};
+
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoNearbyLocation {
+    #[serde(flatten)]
+    pub location: VendoLocationSearchResult,
+    /// The distance to the searched coordinates in meters
+    pub distance: u32,
+}
+
+/// The great circle distance between two coordinates in meters.
+pub fn distance_between(
+    from: &VendoLocationSearchCoordinates,
+    to: &VendoLocationSearchCoordinates,
+) -> u32 {
+    let (from_latitude, to_latitude) = (from.latitude.to_radians(), to.latitude.to_radians());
+    let latitude_delta = to_latitude - from_latitude;
+    let longitude_delta = (to.longitude - from.longitude).to_radians();
+
+    let a = (latitude_delta / 2.0).sin().powi(2)
+        + from_latitude.cos() * to_latitude.cos() * (longitude_delta / 2.0).sin().powi(2);
+
+    (2.0 * EARTH_RADIUS * a.sqrt().asin()).round() as u32
+}
+
+/// Measures the distance of every location to `origin`, \
+/// keeps the `max_results` closest locations within the radius and sorts them by distance.
+pub fn nearby_locations(
+    origin: &VendoLocationSearchCoordinates,
+    locations: impl IntoIterator<Item = VendoLocationSearchResult>,
+    radius: u32,
+    max_results: usize,
+) -> Vec<VendoNearbyLocation> {
+    let mut nearby: Vec<VendoNearbyLocation> = locations
+        .into_iter()
+        .map(|location| VendoNearbyLocation {
+            distance: distance_between(origin, &location.coordinates),
+            location,
+        })
+        .filter(|location| location.distance <= radius)
+        .collect();
+
+    nearby.sort_by_key(|location| location.distance);
+    nearby.truncate(max_results);
+
+    nearby
+}
@@ -10,27 +10,36 @@ use std::collections::BTreeMap;
 use crate::VendoClient;
 use crate::{error::VendoError, VendoOrRequestError};
 
+mod paging;
 mod request;
 pub mod response;
 mod transformed;
 
-use crate::shared::Time;
+use crate::shared::{PagingDirection, Time};
 use crate::station_board::response::{
     StationBoardArrivalsElement, StationBoardArrivalsResponse, StationBoardDeparturesElement,
     StationBoardDeparturesResponse,
 };
+pub use paging::*;
 pub use request::*;
 pub use transformed::*;
 
 impl VendoClient {
+    /// Get the arrivals and departures of a station at a specific date merged by train.
+    ///
+    /// Transport types can be provided to filter the results.
+    /// If no transport types are provided, all transport types are being returned.
     pub async fn station_board(
         &self,
         id: &str,
         date: DateTime<Tz>,
+        transport_types: Option<Vec<VendoTransportType>>,
     ) -> Result<VendoStationBoard, VendoOrRequestError> {
+        let transport_types = transport_types.unwrap_or_else(|| VendoTransportType::ALL.to_vec());
+
         let (arrivals, departures) = tokio::join!(
-            self.station_board_arrivals(id, Some(date), None),
-            self.station_board_departures(id, Some(date), None)
+            self.station_board_arrivals(id, Some(date), Some(transport_types.clone())),
+            self.station_board_departures(id, Some(date), Some(transport_types.clone()))
         );
 
         let arrivals = arrivals?;
@@ -83,6 +92,11 @@ impl VendoClient {
                         scheduled_platform: departure.platform,
                         realtime_platform: departure.realtime_platform,
                         notes: departure.notes.into_iter().map(|note| note.text).collect(),
+                        demand: departure
+                            .demand
+                            .into_iter()
+                            .map(|from| from.into())
+                            .collect(),
                         request_station: StationBoardRequestedStation {
                             eva: departure.requested_station.eva,
                             name: departure.requested_station.name,
@@ -101,6 +115,7 @@ impl VendoClient {
                         scheduled_platform: arrival.platform,
                         realtime_platform: arrival.realtime_platform,
                         notes: arrival.notes.into_iter().map(|note| note.text).collect(),
+                        demand: arrival.demand.into_iter().map(|from| from.into()).collect(),
                         request_station: StationBoardRequestedStation {
                             eva: arrival.requested_station.eva,
                             name: arrival.requested_station.name,
@@ -128,10 +143,101 @@ impl VendoClient {
             day: date.format("%Y-%m-%d").to_string(),
             time: date.format("%H:%M").to_string(),
             id: id.to_string(),
+            transport_types,
+            cursor: StationBoardCursor::for_page(&trains, 0).map(|cursor| cursor.to_string()),
             station_board: trains,
         })
     }
 
+    /// Get the page of the station board before or after the page the cursor was returned with.
+    ///
+    /// The cursor is the one of a [`VendoStationBoard`] returned for the same station and transport types. \
+    /// Earlier pages contain the elements of up to an hour before the page of the cursor, \
+    /// requested in as many responses as needed to leave no gap before the page of the cursor.
+    pub async fn station_board_page(
+        &self,
+        id: &str,
+        cursor: &str,
+        direction: PagingDirection,
+        transport_types: Option<Vec<VendoTransportType>>,
+    ) -> Result<VendoStationBoard, VendoOrRequestError> {
+        let cursor: StationBoardCursor = cursor.parse()?;
+
+        let (mut station_board, start_offset) = match direction {
+            PagingDirection::Later => {
+                let mut station_board = self
+                    .station_board(id, cursor.end.time.with_timezone(&Berlin), transport_types)
+                    .await?;
+
+                let (elements, start_offset) = later_page(station_board.station_board, cursor.end);
+                station_board.station_board = elements;
+
+                (station_board, start_offset)
+            }
+            PagingDirection::Earlier => {
+                let mut station_board = self
+                    .station_board_until(
+                        id,
+                        cursor.start.time.with_timezone(&Berlin) - chrono::Duration::hours(1),
+                        cursor.start,
+                        transport_types,
+                    )
+                    .await?;
+
+                station_board.station_board =
+                    earlier_page(station_board.station_board, cursor.start);
+
+                (station_board, 0)
+            }
+        };
+
+        station_board.cursor =
+            StationBoardCursor::for_page(&station_board.station_board, start_offset)
+                .map(|cursor| cursor.to_string());
+
+        Ok(station_board)
+    }
+
+    /// Get the station board from `date` until at least `until`.
+    ///
+    /// A single response of Vendo only covers a limited amount of elements, which can be less than an hour at busy stations, \
+    /// so the station board is requested again from its last element until it reaches `until`.
+    async fn station_board_until(
+        &self,
+        id: &str,
+        date: DateTime<Tz>,
+        until: StationBoardPosition,
+        transport_types: Option<Vec<VendoTransportType>>,
+    ) -> Result<VendoStationBoard, VendoOrRequestError> {
+        let mut station_board = self
+            .station_board(id, date, transport_types.clone())
+            .await?;
+
+        while let Some(cursor) = StationBoardCursor::for_page(&station_board.station_board, 0) {
+            if cursor.end.time > until.time {
+                break;
+            }
+
+            let next = self
+                .station_board(
+                    id,
+                    cursor.end.time.with_timezone(&Berlin),
+                    transport_types.clone(),
+                )
+                .await?;
+
+            let (elements, _) = later_page(next.station_board, cursor.end);
+
+            if elements.is_empty() {
+                break;
+            }
+
+            station_board.station_board.extend(elements);
+        }
+
+        Ok(station_board)
+    }
+
     /// Get the arrival station board for a station at a specific date.
     ///
     /// The station should be given in the as the eva number (e.G. `8000105`) \
@@ -0,0 +1,163 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+use crate::VendoOrRequestError;
+
+use super::StationBoardElement;
+
+/// A position on a station board: the `offset`th element of all elements at `time`.
+///
+/// Vendo only knows times to the minute, so the offset is needed to continue in the middle of a minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StationBoardPosition {
+    pub time: DateTime<FixedOffset>,
+    pub offset: usize,
+}
+
+/// The first and the position after the last element of a page of a station board.
+///
+/// Formatted as `{start}.{start offset}.{end}.{end offset}` with the times as unix timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StationBoardCursor {
+    pub start: StationBoardPosition,
+    pub end: StationBoardPosition,
+}
+
+impl StationBoardCursor {
+    /// The cursor of a page whose first element is preceded by `start_offset` elements at the same time.
+    ///
+    /// Returns `None` for an empty page, since it has no position to continue from.
+    pub fn for_page(elements: &[StationBoardElement], start_offset: usize) -> Option<Self> {
+        let start = board_time(elements.first()?);
+        let end = board_time(elements.last()?);
+
+        let mut end_offset = elements
+            .iter()
+            .filter(|element| board_time(element) == end)
+            .count();
+
+        if start == end {
+            end_offset += start_offset;
+        }
+
+        Some(Self {
+            start: StationBoardPosition {
+                time: start,
+                offset: start_offset,
+            },
+            end: StationBoardPosition {
+                time: end,
+                offset: end_offset,
+            },
+        })
+    }
+}
+
+impl Display for StationBoardCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.start.time.timestamp(),
+            self.start.offset,
+            self.end.time.timestamp(),
+            self.end.offset
+        )
+    }
+}
+
+impl FromStr for StationBoardCursor {
+    type Err = VendoOrRequestError;
+
+    fn from_str(cursor: &str) -> Result<Self, Self::Err> {
+        let invalid = || VendoOrRequestError::InvalidCursor {
+            cursor: cursor.to_string(),
+        };
+
+        let parts = cursor
+            .split('.')
+            .map(|part| part.parse::<i64>().ok())
+            .collect::<Option<Vec<i64>>>()
+            .ok_or_else(invalid)?;
+
+        let [start, start_offset, end, end_offset] = parts[..] else {
+            return Err(invalid());
+        };
+
+        let position = |time: i64, offset: i64| {
+            Some(StationBoardPosition {
+                time: Utc.timestamp_opt(time, 0).single()?.fixed_offset(),
+                offset: usize::try_from(offset).ok()?,
+            })
+        };
+
+        Ok(Self {
+            start: position(start, start_offset).ok_or_else(invalid)?,
+            end: position(end, end_offset).ok_or_else(invalid)?,
+        })
+    }
+}
+
+/// Keeps the elements after `end`, returns them with the offset of the first kept element.
+pub fn later_page(
+    mut elements: Vec<StationBoardElement>,
+    end: StationBoardPosition,
+) -> (Vec<StationBoardElement>, usize) {
+    let mut at_end = 0;
+
+    elements.retain(|element| {
+        let time = board_time(element);
+
+        if time == end.time {
+            at_end += 1;
+            at_end > end.offset
+        } else {
+            time > end.time
+        }
+    });
+
+    let start_offset = match elements.first() {
+        Some(first) if board_time(first) == end.time => end.offset,
+        _ => 0,
+    };
+
+    (elements, start_offset)
+}
+
+/// Keeps the elements before `start`.
+pub fn earlier_page(
+    mut elements: Vec<StationBoardElement>,
+    start: StationBoardPosition,
+) -> Vec<StationBoardElement> {
+    let mut at_start = 0;
+
+    elements.retain(|element| {
+        let time = board_time(element);
+
+        if time == start.time {
+            at_start += 1;
+            at_start <= start.offset
+        } else {
+            time < start.time
+        }
+    });
+
+    elements
+}
+
+/// The scheduled time a station board is sorted by, the departure if there is one.
+pub(crate) fn board_time(element: &StationBoardElement) -> DateTime<FixedOffset> {
+    element
+        .departure
+        .as_ref()
+        .map(|departure| departure.time.scheduled)
+        .or_else(|| {
+            element
+                .arrival
+                .as_ref()
+                .map(|arrival| arrival.time.scheduled)
+        })
+        .expect("a station board element has an arrival or a departure")
+}
@@ -1,4 +1,8 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VendoStationBoardRequest {
@@ -12,7 +16,7 @@ pub struct VendoStationBoardRequest {
     pub transport_types: Vec<VendoTransportType>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, ToSchema)]
 pub enum VendoTransportType {
     #[serde(rename = "HOCHGESCHWINDIGKEITSZUEGE")]
     HighspeedTrains,
@@ -50,3 +54,54 @@ impl VendoTransportType {
         VendoTransportType::CallRequiringTransportTypes,
     ];
 }
+
+impl VendoTransportType {
+    /// A short name of the transport type, e.G. `ICE` for [`VendoTransportType::HighspeedTrains`].
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            VendoTransportType::HighspeedTrains => "ICE",
+            VendoTransportType::ICAndECTrains => "IC",
+            VendoTransportType::InterregionalAndFastTrains => "IR",
+            VendoTransportType::RegionalAndOtherTrains => "REGIONAL",
+            VendoTransportType::SuburbanTrains => "SBAHN",
+            VendoTransportType::Busses => "BUS",
+            VendoTransportType::Boats => "SHIP",
+            VendoTransportType::Subway => "UBAHN",
+            VendoTransportType::Tram => "TRAM",
+            VendoTransportType::CallRequiringTransportTypes => "TAXI",
+        }
+    }
+}
+
+impl Display for VendoTransportType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.short_name())
+    }
+}
+
+impl FromStr for VendoTransportType {
+    type Err = String;
+
+    /// Accepts the short names as well as the names Vendo uses, ignoring case.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_uppercase().as_str() {
+            "ICE" | "HOCHGESCHWINDIGKEITSZUEGE" => Ok(VendoTransportType::HighspeedTrains),
+            "IC" | "EC" | "INTERCITYUNDEUROCITYZUEGE" => Ok(VendoTransportType::ICAndECTrains),
+            "IR" | "INTERREGIOUNDSCHNELLZUEGE" => {
+                Ok(VendoTransportType::InterregionalAndFastTrains)
+            }
+            "REGIONAL" | "NAHVERKEHRSONSTIGEZUEGE" => {
+                Ok(VendoTransportType::RegionalAndOtherTrains)
+            }
+            "SBAHN" | "SBAHNEN" => Ok(VendoTransportType::SuburbanTrains),
+            "BUS" | "BUSSE" => Ok(VendoTransportType::Busses),
+            "SHIP" | "SCHIFFE" => Ok(VendoTransportType::Boats),
+            "UBAHN" => Ok(VendoTransportType::Subway),
+            "TRAM" | "STRASSENBAHN" => Ok(VendoTransportType::Tram),
+            "TAXI" | "ANRUFPFLICHTIGEVERKEHRE" => {
+                Ok(VendoTransportType::CallRequiringTransportTypes)
+            }
+            _ => Err(format!("Unknown transport type: {value}")),
+        }
+    }
+}
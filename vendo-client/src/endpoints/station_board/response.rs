@@ -1,6 +1,8 @@
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
+use crate::journey_details::response::JourneyDetailsStopDemand;
+
 // Arrivals
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StationBoardArrivalsResponse {
@@ -32,6 +34,9 @@ pub struct StationBoardArrivalsElement {
     pub notes: Vec<Note>,
     #[serde(rename = "produktGattung")]
     pub product_type: String,
+    /// Only set for some trains
+    #[serde(rename = "auslastungsInfos", default)]
+    pub demand: Vec<JourneyDetailsStopDemand>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +79,9 @@ pub struct StationBoardDeparturesElement {
     pub notes: Vec<Note>,
     #[serde(rename = "produktGattung")]
     pub product_type: String,
+    /// Only set for some trains
+    #[serde(rename = "auslastungsInfos", default)]
+    pub demand: Vec<JourneyDetailsStopDemand>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
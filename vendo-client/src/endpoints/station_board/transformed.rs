@@ -1,4 +1,5 @@
-use crate::shared::Time;
+use crate::shared::{Time, VendoDemand};
+use crate::station_board::VendoTransportType;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -8,6 +9,12 @@ pub struct VendoStationBoard {
     pub day: String,
     pub time: String,
     pub id: String,
+    /// The transport types the station board is filtered to
+    pub transport_types: Vec<VendoTransportType>,
+    /// Pass this to [`VendoClient::station_board_page`](crate::VendoClient::station_board_page) to get earlier or later elements, \
+    /// not set if the station board is empty
+    #[schema(nullable)]
+    pub cursor: Option<String>,
     pub station_board: Vec<StationBoardElement>,
 }
 
@@ -27,6 +34,8 @@ pub struct StationBoardElement {
     #[schema(nullable)]
     pub realtime_platform: Option<String>,
     pub notes: Vec<String>,
+    /// The expected occupancy for every class Vendo has a forecast for
+    pub demand: Vec<VendoDemand>,
     pub request_station: StationBoardRequestedStation,
 }
 
@@ -16,4 +16,6 @@ pub enum VendoOrRequestError {
     VendoError(#[from] VendoError),
     #[error(transparent)]
     FailedRequest(#[from] reqwest::Error),
+    #[error("The paging cursor {cursor} is invalid.")]
+    InvalidCursor { cursor: String },
 }
@@ -26,3 +26,73 @@ pub struct Attribute {
     pub text: String,
     pub key: String,
 }
+
+/// Whether to page to earlier or later results than the ones a cursor was returned with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum PagingDirection {
+    Earlier,
+    #[default]
+    Later,
+}
+
+/// The expected occupancy of a train in one class.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VendoDemand {
+    pub class: VendoDemandClass,
+    pub level: VendoDemandLevel,
+    /// The description of the level by Vendo, e.G. `Hohe Auslastung erwartet`
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq, Hash)]
+pub enum VendoDemandClass {
+    FirstClass,
+    SecondClass,
+}
+
+/// Ordered from the lowest to the highest occupancy, `Unknown` being lower than all others.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum VendoDemandLevel {
+    Unknown,
+    Low,
+    Medium,
+    High,
+    FullyBooked,
+}
+
+impl From<u32> for VendoDemandLevel {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => VendoDemandLevel::Low,
+            2 => VendoDemandLevel::Medium,
+            3 => VendoDemandLevel::High,
+            4 => VendoDemandLevel::FullyBooked,
+            _ => VendoDemandLevel::Unknown,
+        }
+    }
+}
+
+impl VendoDemand {
+    /// The highest demand of every class, the first stop wins if several have the same level.
+    pub fn max_per_class<'a>(
+        demands: impl IntoIterator<Item = &'a VendoDemand>,
+    ) -> Vec<VendoDemand> {
+        let mut max: Vec<VendoDemand> = Vec::new();
+
+        for demand in demands {
+            match max.iter_mut().find(|max| max.class == demand.class) {
+                Some(max) if demand.level > max.level => *max = demand.clone(),
+                Some(_) => {}
+                None => max.push(demand.clone()),
+            }
+        }
+
+        max.sort_by_key(|demand| demand.class == VendoDemandClass::SecondClass);
+
+        max
+    }
+}
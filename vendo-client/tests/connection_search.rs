@@ -0,0 +1,110 @@
+use chrono::TimeZone;
+use chrono_tz::Europe::Berlin;
+use vendo_client::connection_search::response::ConnectionSearchResponse;
+use vendo_client::connection_search::{
+    VendoConnectionSearch, VendoConnectionSectionType, VendoConnectionTime,
+};
+use vendo_client::VendoClient;
+
+const CONNECTIONS: &str = include_str!("fixtures/connection_search.json");
+
+fn connection_search() -> VendoConnectionSearch {
+    let response: ConnectionSearchResponse =
+        serde_json::from_str(CONNECTIONS).expect("Failed to parse connection search fixture");
+
+    response.into()
+}
+
+#[tokio::test]
+async fn connection_search_request() {
+    let vendo_client = VendoClient::default();
+
+    let time =
+        Berlin.from_utc_datetime(&chrono::Utc::now().naive_utc()) + chrono::Duration::hours(1);
+
+    let connections = vendo_client
+        .connection_search(
+            "8000105",
+            "8000261",
+            None,
+            Some(VendoConnectionTime::Departure(time)),
+            None,
+            Some(2),
+        )
+        .await
+        .expect("Failed to get connections");
+
+    assert!(!connections.connections.is_empty(), "No connections found");
+
+    for connection in connections.connections {
+        assert!(connection.transfer_count <= 2);
+        assert_eq!(
+            connection.sections.first().unwrap().origin.eva.as_deref(),
+            Some("8000105")
+        );
+    }
+}
+
+#[test]
+fn connections_without_sections_are_dropped() {
+    let connections = connection_search();
+
+    assert_eq!(connections.connections.len(), 1);
+}
+
+#[test]
+fn connection_is_transformed() {
+    let connection = connection_search().connections.remove(0);
+
+    assert_eq!(connection.duration, 200);
+    assert_eq!(connection.transfer_count, 1);
+    assert_eq!(connection.sections.len(), 3);
+    assert_eq!(
+        connection.departure.scheduled,
+        Berlin.with_ymd_and_hms(2024, 1, 15, 10, 50, 0).unwrap()
+    );
+    assert_eq!(
+        connection.departure.realtime,
+        Some(
+            Berlin
+                .with_ymd_and_hms(2024, 1, 15, 10, 55, 0)
+                .unwrap()
+                .fixed_offset()
+        )
+    );
+    assert_eq!(connection.arrival.realtime, None);
+    assert_eq!(
+        connection.notes,
+        vec!["Anschluss wird voraussichtlich erreicht"]
+    );
+
+    let ice = &connection.sections[0];
+
+    assert_eq!(ice.section_type, VendoConnectionSectionType::Transport);
+    assert_eq!(ice.name.as_deref(), Some("ICE 527"));
+    assert_eq!(ice.departure_platform.as_deref(), Some("7"));
+    assert_eq!(ice.realtime_arrival_platform.as_deref(), Some("8"));
+    assert_eq!(ice.stops.len(), 3);
+    assert_eq!(ice.stops[2].notes, vec!["Gleiswechsel"]);
+    assert_eq!(ice.attributes[0].key, "BR");
+
+    assert_eq!(
+        connection.sections[1].section_type,
+        VendoConnectionSectionType::Transfer
+    );
+}
+
+#[test]
+fn transfers_include_delays() {
+    let connection = connection_search().connections.remove(0);
+
+    assert_eq!(connection.transfers.len(), 1);
+
+    let transfer = &connection.transfers[0];
+
+    assert_eq!(transfer.from.eva.as_deref(), Some("8000284"));
+    assert_eq!(transfer.from_platform.as_deref(), Some("8"));
+    assert_eq!(transfer.to_platform.as_deref(), Some("14"));
+    assert_eq!(transfer.scheduled_duration, 15);
+    assert_eq!(transfer.realtime_duration, Some(8));
+}
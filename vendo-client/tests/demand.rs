@@ -0,0 +1,54 @@
+use vendo_client::journey_details::response::JourneyDetailsStopDemand;
+use vendo_client::shared::{VendoDemand, VendoDemandClass, VendoDemandLevel};
+
+fn demands(json: &str) -> Vec<VendoDemand> {
+    let demands: Vec<JourneyDetailsStopDemand> =
+        serde_json::from_str(json).expect("Failed to parse demands");
+
+    demands.into_iter().map(VendoDemand::from).collect()
+}
+
+#[test]
+fn demand_is_transformed() {
+    let demands = demands(
+        r#"[
+            { "klasse": "KLASSE_1", "stufe": 1, "anzeigeTextKurz": "Geringe Auslastung erwartet" },
+            { "klasse": "KLASSE_2", "stufe": 4, "anzeigeTextKurz": "Zug ist ausgebucht" }
+        ]"#,
+    );
+
+    assert_eq!(demands[0].class, VendoDemandClass::FirstClass);
+    assert_eq!(demands[0].level, VendoDemandLevel::Low);
+    assert_eq!(demands[1].class, VendoDemandClass::SecondClass);
+    assert_eq!(demands[1].level, VendoDemandLevel::FullyBooked);
+    assert_eq!(demands[1].text, "Zug ist ausgebucht");
+}
+
+#[test]
+fn unknown_levels_are_the_lowest() {
+    assert_eq!(VendoDemandLevel::from(0), VendoDemandLevel::Unknown);
+    assert_eq!(VendoDemandLevel::from(99), VendoDemandLevel::Unknown);
+    assert!(VendoDemandLevel::Unknown < VendoDemandLevel::Low);
+}
+
+#[test]
+fn max_demand_is_found_per_class() {
+    let demands = demands(
+        r#"[
+            { "klasse": "KLASSE_2", "stufe": 2, "anzeigeTextKurz": "Mittlere Auslastung erwartet" },
+            { "klasse": "KLASSE_1", "stufe": 1, "anzeigeTextKurz": "Geringe Auslastung erwartet" },
+            { "klasse": "KLASSE_2", "stufe": 3, "anzeigeTextKurz": "Hohe Auslastung erwartet" },
+            { "klasse": "KLASSE_1", "stufe": 1, "anzeigeTextKurz": "Geringe Auslastung" },
+            { "klasse": "KLASSE_2", "stufe": 0, "anzeigeTextKurz": "" }
+        ]"#,
+    );
+
+    let max = VendoDemand::max_per_class(&demands);
+
+    assert_eq!(max.len(), 2);
+    assert_eq!(max[0].class, VendoDemandClass::FirstClass);
+    assert_eq!(max[0].text, "Geringe Auslastung erwartet");
+    assert_eq!(max[1].level, VendoDemandLevel::High);
+
+    assert!(VendoDemand::max_per_class(&[]).is_empty());
+}
@@ -0,0 +1,109 @@
+use vendo_client::location_search::{
+    VendoLocationSearchCoordinates, VendoLocationSearchResult, VendoLocationType,
+};
+use vendo_client::nearby::{distance_between, nearby_locations};
+use vendo_client::VendoClient;
+
+fn coordinates(latitude: f64, longitude: f64) -> VendoLocationSearchCoordinates {
+    VendoLocationSearchCoordinates {
+        latitude,
+        longitude,
+    }
+}
+
+fn station(name: &str, latitude: f64, longitude: f64) -> VendoLocationSearchResult {
+    VendoLocationSearchResult {
+        coordinates: coordinates(latitude, longitude),
+        eva_nr: None,
+        location_id: format!("A=1@O={name}@"),
+        location_type: VendoLocationType::Station,
+        name: name.to_string(),
+        products: Vec::new(),
+        weight: None,
+    }
+}
+
+#[test]
+fn distance_between_coordinates() {
+    let frankfurt_hbf = coordinates(50.107145, 8.663789);
+    let frankfurt_sued = coordinates(50.099365, 8.686457);
+
+    let distance = distance_between(&frankfurt_hbf, &frankfurt_sued);
+
+    assert!(
+        (1750..=1850).contains(&distance),
+        "Distance between Frankfurt Hbf and Frankfurt Süd was {distance}m"
+    );
+    assert_eq!(distance_between(&frankfurt_hbf, &frankfurt_hbf), 0);
+}
+
+#[test]
+fn nearby_locations_are_filtered_and_sorted() {
+    let origin = coordinates(50.107145, 8.663789);
+
+    let locations = vec![
+        station("Frankfurt(Main)Süd", 50.099365, 8.686457),
+        station("Frankfurt(Main)Hbf", 50.107145, 8.663789),
+        station("Frankfurt(Main)Flughafen", 50.052926, 8.570184),
+        station("Frankfurt(Main)Galluswarte", 50.104269, 8.644536),
+    ];
+
+    let nearby = nearby_locations(&origin, locations.clone(), 2000, 10);
+
+    assert_eq!(
+        nearby
+            .iter()
+            .map(|location| location.location.name.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "Frankfurt(Main)Hbf",
+            "Frankfurt(Main)Galluswarte",
+            "Frankfurt(Main)Süd"
+        ]
+    );
+    assert!(nearby
+        .windows(2)
+        .all(|pair| pair[0].distance <= pair[1].distance));
+
+    let nearby = nearby_locations(&origin, locations, 2000, 1);
+
+    assert_eq!(nearby.len(), 1);
+    assert_eq!(nearby[0].distance, 0);
+}
+
+#[test]
+fn location_types_are_parsed() {
+    let types: Vec<VendoLocationType> =
+        serde_json::from_str(r#"["ST", "ADR", "POI", "MCP"]"#).unwrap();
+
+    assert_eq!(
+        types,
+        vec![
+            VendoLocationType::Station,
+            VendoLocationType::Address,
+            VendoLocationType::PointOfInterest,
+            VendoLocationType::Unknown
+        ]
+    );
+}
+
+#[tokio::test]
+async fn nearby() {
+    let client = VendoClient::default();
+
+    let response = client
+        .nearby(50.107145, 8.663789, Some(1000), Some(10), None)
+        .await;
+
+    assert!(response.is_ok(), "Return of nearby search is not ok");
+
+    let response = response.unwrap();
+
+    assert!(
+        response
+            .iter()
+            .any(|location| location.location.name == "Frankfurt(Main)Hbf"),
+        "Frankfurt(Main)Hbf was not found around its coordinates"
+    );
+    assert!(response.iter().all(|location| location.distance <= 1000));
+}
@@ -0,0 +1,276 @@
+use chrono::{DateTime, FixedOffset, TimeZone};
+use chrono_tz::Europe::Berlin;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use vendo_client::connection_search::response::ConnectionSearchResponse;
+use vendo_client::connection_search::{ConnectionSearchCursor, VendoConnectionSearch};
+use vendo_client::shared::{PagingDirection, Time};
+use vendo_client::station_board::{
+    earlier_page, later_page, StationBoardCursor, StationBoardDeparture, StationBoardElement,
+    StationBoardPosition, StationBoardRequestedStation,
+};
+use vendo_client::VendoClient;
+
+const DEPARTURES_0900: &str = include_str!("fixtures/station_board_departures_0900.json");
+const DEPARTURES_0920: &str = include_str!("fixtures/station_board_departures_0920.json");
+const NO_ARRIVALS: &str = r#"{"bahnhofstafelAnkunftPositionen":[]}"#;
+
+fn time(hour: u32, minute: u32) -> DateTime<FixedOffset> {
+    Berlin
+        .with_ymd_and_hms(2024, 1, 15, hour, minute, 0)
+        .unwrap()
+        .fixed_offset()
+}
+
+fn departure(id: &str, hour: u32, minute: u32) -> StationBoardElement {
+    StationBoardElement {
+        journey_id: id.to_string(),
+        arrival: None,
+        departure: Some(StationBoardDeparture {
+            destination: String::from("München Hbf"),
+            time: Time {
+                scheduled: time(hour, minute),
+                realtime: None,
+            },
+        }),
+        product_type: String::from("ICE"),
+        short_name: String::from("ICE"),
+        name: format!("ICE {id}"),
+        scheduled_platform: None,
+        realtime_platform: None,
+        notes: vec![],
+        demand: vec![],
+        request_station: StationBoardRequestedStation {
+            eva: String::from("8000105"),
+            name: String::from("Frankfurt(Main)Hbf"),
+            location_id: String::from("A=1@L=8000105@"),
+        },
+    }
+}
+
+fn ids(elements: &[StationBoardElement]) -> Vec<&str> {
+    elements
+        .iter()
+        .map(|element| element.journey_id.as_str())
+        .collect()
+}
+
+#[test]
+fn station_board_cursor_round_trips() {
+    let page = vec![
+        departure("1", 10, 0),
+        departure("2", 10, 5),
+        departure("3", 10, 5),
+    ];
+
+    let cursor = StationBoardCursor::for_page(&page, 0).expect("Page is empty");
+
+    assert_eq!(cursor.start.time, time(10, 0));
+    assert_eq!(cursor.start.offset, 0);
+    assert_eq!(cursor.end.time, time(10, 5));
+    assert_eq!(cursor.end.offset, 2);
+
+    let parsed: StationBoardCursor = cursor.to_string().parse().expect("Failed to parse");
+    assert_eq!(parsed, cursor);
+
+    assert!("10.0.1".parse::<StationBoardCursor>().is_err());
+    assert!("a.0.1.0".parse::<StationBoardCursor>().is_err());
+    assert!(StationBoardCursor::for_page(&[], 0).is_none());
+}
+
+#[test]
+fn later_page_continues_within_a_minute() {
+    let first_page = vec![departure("1", 10, 0), departure("2", 10, 5)];
+    let cursor = StationBoardCursor::for_page(&first_page, 0).unwrap();
+
+    // Requesting the board at the end of the cursor returns the last element of the first page again
+    let board = vec![
+        departure("2", 10, 5),
+        departure("3", 10, 5),
+        departure("4", 10, 10),
+    ];
+
+    let (page, start_offset) = later_page(board, cursor.end);
+
+    assert_eq!(ids(&page), vec!["3", "4"]);
+    assert_eq!(start_offset, 1);
+
+    let cursor = StationBoardCursor::for_page(&page, start_offset).unwrap();
+    assert_eq!(cursor.start.offset, 1);
+    assert_eq!(cursor.end.offset, 1);
+}
+
+#[test]
+fn earlier_page_ends_before_the_cursor() {
+    let cursor =
+        StationBoardCursor::for_page(&[departure("3", 10, 5), departure("4", 10, 10)], 1).unwrap();
+
+    let board = vec![
+        departure("0", 9, 30),
+        departure("1", 10, 0),
+        departure("2", 10, 5),
+        departure("3", 10, 5),
+        departure("4", 10, 10),
+    ];
+
+    let page = earlier_page(board, cursor.start);
+
+    assert_eq!(ids(&page), vec!["0", "1", "2"]);
+}
+
+/// Starts a stand-in server that answers `requests` station board requests with the departures for the requested time
+/// and no arrivals, returns the requested times of the departures.
+async fn stand_in_server(
+    requests: usize,
+    departures: Vec<(&'static str, &'static str)>,
+) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in server");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let mut times = Vec::new();
+
+        for _ in 0..requests {
+            let (mut stream, _) = listener.accept().await.expect("No request received");
+
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            let (head, body) = loop {
+                let read = stream
+                    .read(&mut buffer)
+                    .await
+                    .expect("Failed to read request");
+                request.extend_from_slice(&buffer[..read]);
+
+                let request = String::from_utf8_lossy(&request).into_owned();
+                if let Some((head, body)) = request.split_once("\r\n\r\n") {
+                    let length = head
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|length| length.trim().parse::<usize>().unwrap())
+                        })
+                        .unwrap_or_default();
+
+                    if body.len() >= length || read == 0 {
+                        break (head.to_owned(), body.to_owned());
+                    }
+                }
+            };
+
+            let time = serde_json::from_str::<serde_json::Value>(&body)
+                .expect("Request body is not json")["anfragezeit"]
+                .as_str()
+                .unwrap()
+                .to_owned();
+
+            let response = if head.contains("/abfahrt") {
+                times.push(time.clone());
+                departures
+                    .iter()
+                    .find(|(departure_time, _)| *departure_time == time)
+                    .map(|(_, body)| *body)
+                    .unwrap_or_else(|| panic!("Unexpected departures request at {time}"))
+            } else {
+                NO_ARRIVALS
+            };
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{response}",
+                response.len()
+            );
+            stream
+                .write_all(response.as_bytes())
+                .await
+                .expect("Failed to write response");
+        }
+
+        times
+    });
+
+    (url, handle)
+}
+
+#[tokio::test]
+async fn earlier_page_has_no_gaps() {
+    // The response at 09:00 ends at 09:20, so the board has to be requested again to reach the cursor at 10:00
+    let (url, times) = stand_in_server(
+        4,
+        vec![("09:00", DEPARTURES_0900), ("09:20", DEPARTURES_0920)],
+    )
+    .await;
+
+    let client = VendoClient::new(None, Some(url), None);
+
+    let cursor = StationBoardCursor {
+        start: StationBoardPosition {
+            time: time(10, 0),
+            offset: 0,
+        },
+        end: StationBoardPosition {
+            time: time(10, 10),
+            offset: 1,
+        },
+    };
+
+    let page = client
+        .station_board_page(
+            "8000105",
+            &cursor.to_string(),
+            PagingDirection::Earlier,
+            None,
+        )
+        .await
+        .expect("Failed to get the earlier page from the stand-in server");
+
+    assert_eq!(
+        page.station_board
+            .iter()
+            .map(|element| element.departure.as_ref().unwrap().time.scheduled)
+            .collect::<Vec<_>>(),
+        vec![time(9, 0), time(9, 20), time(9, 40)]
+    );
+
+    let mut times = times.await.unwrap();
+    times.sort();
+    assert_eq!(times, vec!["09:00", "09:20"]);
+}
+
+#[test]
+fn connection_search_cursor_round_trips() {
+    let response: ConnectionSearchResponse =
+        serde_json::from_str(include_str!("fixtures/connection_search.json"))
+            .expect("Failed to parse connection search fixture");
+
+    let earlier = response.earlier_context.clone();
+    let later = response.later_context.clone();
+
+    let connections: VendoConnectionSearch = response.into();
+
+    let cursor: ConnectionSearchCursor = connections
+        .cursor
+        .expect("No cursor")
+        .parse()
+        .expect("Failed to parse");
+
+    assert_eq!(cursor.earlier, earlier);
+    assert_eq!(cursor.later, later);
+
+    let only_later = ConnectionSearchCursor {
+        earlier: None,
+        later: Some(String::from("a,b")),
+    };
+
+    assert_eq!(
+        only_later
+            .to_string()
+            .parse::<ConnectionSearchCursor>()
+            .unwrap(),
+        only_later
+    );
+    assert!("no separator".parse::<ConnectionSearchCursor>().is_err());
+}
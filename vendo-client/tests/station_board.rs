@@ -95,3 +95,23 @@ async fn station_board_arrivals_filtered() {
         "Not High-speed Train found in response"
     )
 }
+
+#[test]
+fn transport_types_are_parsed() {
+    for transport_type in VendoTransportType::ALL {
+        assert_eq!(
+            transport_type.short_name().parse::<VendoTransportType>(),
+            Ok(transport_type)
+        );
+    }
+
+    assert_eq!(
+        "sbahn".parse::<VendoTransportType>(),
+        Ok(VendoTransportType::SuburbanTrains)
+    );
+    assert_eq!(
+        "HOCHGESCHWINDIGKEITSZUEGE".parse::<VendoTransportType>(),
+        Ok(VendoTransportType::HighspeedTrains)
+    );
+    assert!("HOVERCRAFT".parse::<VendoTransportType>().is_err());
+}